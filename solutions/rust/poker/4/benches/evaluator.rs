@@ -0,0 +1,94 @@
+//! Compare the lookup-table evaluator, used by `Hand::ranking`, against the rank-counting
+//! classification it replaced.
+//!
+//! Run with `cargo bench --bench evaluator`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use poker::evaluator::{evaluate, CardSet};
+use poker::{Hand, RankingCategory};
+
+const HAND_COUNT: usize = 10_000;
+const ROUNDS: usize = 20;
+
+fn random_hands() -> Vec<String> {
+    let deck: Vec<String> = "CDHS".chars()
+        .flat_map(|suit| ["2", "3", "4", "5", "6", "7", "8", "9", "10", "J", "Q", "K", "A"]
+            .map(|rank| format!("{}{}", rank, suit)))
+        .collect();
+    let mut rng = StdRng::seed_from_u64(2024);
+
+    (0..HAND_COUNT)
+        .map(|_| deck.choose_multiple(&mut rng, 5).cloned().collect::<Vec<_>>().join(" "))
+        .collect()
+}
+
+/// The category as `Hand::ranking` used to work it out, counting ranks in a `HashMap`.
+#[allow(deprecated)]
+fn hashmap_category(hand: &Hand) -> RankingCategory {
+    let a_straight = hand.a_straight();
+    let a_flush = hand.a_flush();
+
+    if a_straight && a_flush { return RankingCategory::StraightFlush; }
+    if a_flush { return RankingCategory::Flush; }
+    if a_straight { return RankingCategory::Straight; }
+    if hand.a_quads() { return RankingCategory::Quads; }
+    if hand.a_full() { return RankingCategory::Full; }
+    if hand.a_trips() { return RankingCategory::Trips; }
+    if hand.a_two_pair() { return RankingCategory::TwoPair; }
+    if hand.a_one_pair() { return RankingCategory::OnePair; }
+
+    RankingCategory::HighestCard
+}
+
+fn time_it<F: FnMut() -> u64>(mut run: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(run());
+    }
+    start.elapsed()
+}
+
+fn report(name: &str, elapsed: Duration) {
+    let per_hand = elapsed.as_nanos() as f64 / (HAND_COUNT * ROUNDS) as f64;
+    println!("{:<28} {:>10.1?} total, {:>8.1} ns/hand", name, elapsed, per_hand);
+}
+
+fn main() {
+    let hands = random_hands();
+    let hand_structs: Vec<Hand> = hands.iter().map(|h| Hand::from_string(h)).collect();
+    let card_sets: Vec<CardSet> = hands.iter().map(|h| h.parse().unwrap()).collect();
+
+    assert!(hand_structs.iter().all(|h| hashmap_category(h) == h.ranking_category()));
+
+    let parse_and_count = time_it(|| hands.iter()
+        .map(|h| hashmap_category(&Hand::from_string(h)) as u64)
+        .sum());
+    let parse_and_evaluate = time_it(|| hands.iter()
+        .map(|h| evaluate(h.parse().unwrap()) as u64)
+        .sum());
+    let count_only = time_it(|| hand_structs.iter()
+        .map(|h| hashmap_category(h) as u64)
+        .sum());
+    let rank_only = time_it(|| hand_structs.iter()
+        .map(|h| h.ranking_category() as u64)
+        .sum());
+    let evaluate_only = time_it(|| card_sets.iter()
+        .map(|&cards| evaluate(cards) as u64)
+        .sum());
+
+    report("Hand::from_string + HashMap", parse_and_count);
+    report("CardSet::from_str + evaluate", parse_and_evaluate);
+    report("HashMap counting", count_only);
+    report("Hand::ranking_category", rank_only);
+    report("evaluate", evaluate_only);
+    println!("speedup over HashMap counting: {:.1}x (parse + rank), {:.1}x (ranking_category), {:.1}x (evaluate)",
+             parse_and_count.as_secs_f64() / parse_and_evaluate.as_secs_f64(),
+             count_only.as_secs_f64() / rank_only.as_secs_f64(),
+             count_only.as_secs_f64() / evaluate_only.as_secs_f64());
+}
@@ -0,0 +1,218 @@
+//! Bots and a self-play arena to pit them against each other.
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::deck::Deck;
+use crate::game::{Action, Blinds, Game, GameError, LegalActions, Street};
+use crate::holdem::best_hand_from;
+use crate::{Card, Rank, RankingCategory};
+
+/// What a player is allowed to see when it is their turn: their own hole cards and
+/// the public state of the table.
+pub struct GameView<'a> {
+    game: &'a Game,
+    seat: usize,
+}
+
+impl GameView<'_> {
+    pub fn seat(&self) -> usize {
+        self.seat
+    }
+
+    pub fn hole_cards(&self) -> [Card; 2] {
+        self.game.hole_cards(self.seat)
+    }
+
+    pub fn board(&self) -> &[Card] {
+        self.game.board()
+    }
+
+    pub fn street(&self) -> Street {
+        self.game.street()
+    }
+
+    pub fn blinds(&self) -> Blinds {
+        self.game.blinds()
+    }
+
+    pub fn player_count(&self) -> usize {
+        self.game.player_count()
+    }
+
+    pub fn button(&self) -> usize {
+        self.game.button()
+    }
+
+    pub fn pot(&self) -> u64 {
+        self.game.pot()
+    }
+
+    pub fn stack(&self, seat: usize) -> u64 {
+        self.game.stack(seat)
+    }
+
+    pub fn street_bet(&self, seat: usize) -> u64 {
+        self.game.street_bet(seat)
+    }
+
+    pub fn has_folded(&self, seat: usize) -> bool {
+        self.game.has_folded(seat)
+    }
+
+    pub fn legal_actions(&self) -> LegalActions {
+        self.game.legal_actions().unwrap()
+    }
+
+    /// The category of the best hand made with the board, `None` before the flop.
+    pub fn made_category(&self) -> Option<RankingCategory> {
+        let cards = Vec::from_iter(self.hole_cards().into_iter().chain(self.board().iter().copied()));
+        best_hand_from(&cards).map(|best| best.hand.ranking_category())
+    }
+}
+
+/// A strategy, asked for an action every time its seat is to act.
+///
+/// An illegal action is taken as a fold.
+pub trait Player {
+    fn act(&mut self, view: &GameView) -> Action;
+}
+
+/// Never folds nor raises: checks when it can, calls otherwise.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlwaysCall;
+
+impl Player for AlwaysCall {
+    fn act(&mut self, view: &GameView) -> Action {
+        if view.legal_actions().can_check { Action::Check } else { Action::Call }
+    }
+}
+
+/// Plays by the strength of its hand, betting and raising the minimum with strong hands,
+/// calling with fair ones and giving up with the rest.
+#[derive(Debug, Clone, Copy)]
+pub struct HandStrengthThreshold {
+    /// Before the flop, pairs and hands with both cards at least this rank are played.
+    pub preflop_rank: Rank,
+    /// After the flop, call with at least this category...
+    pub call_with: RankingCategory,
+    /// ...and bet or raise with at least this one.
+    pub raise_with: RankingCategory,
+}
+
+impl Default for HandStrengthThreshold {
+    fn default() -> Self {
+        HandStrengthThreshold {
+            preflop_rank: Rank::Ten,
+            call_with: RankingCategory::OnePair,
+            raise_with: RankingCategory::TwoPair,
+        }
+    }
+}
+
+impl Player for HandStrengthThreshold {
+    fn act(&mut self, view: &GameView) -> Action {
+        let legal = view.legal_actions();
+        let (playable, strong) = match view.made_category() {
+            Some(category) => (category >= self.call_with, category >= self.raise_with),
+            None => {
+                let [first, second] = view.hole_cards();
+                let pair = first.rank == second.rank;
+                (pair || first.rank.min(second.rank) >= self.preflop_rank, pair && first.rank >= self.preflop_rank)
+            }
+        };
+
+        if strong {
+            if let Some(to) = legal.min_raise_to {
+                return Action::Raise(to);
+            }
+            if let Some(to) = legal.min_bet {
+                return Action::Bet(to);
+            }
+        }
+        match (legal.can_check, playable) {
+            (true, _) => Action::Check,
+            (false, true) => Action::Call,
+            (false, false) => Action::Fold,
+        }
+    }
+}
+
+/// How one player did over all the hands of an arena run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PlayerStats {
+    /// Hands which ended with more chips than they started with.
+    pub hands_won: u64,
+    pub net_chips: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArenaStats {
+    pub hands: u64,
+    pub big_blind: u64,
+    /// In the order of the players given to the arena.
+    pub players: Vec<PlayerStats>,
+}
+
+impl ArenaStats {
+    /// The share of the hands `player` won chips in, 0 when no hand was played.
+    pub fn win_rate(&self, player: usize) -> f64 {
+        if self.hands == 0 {
+            return 0.0;
+        }
+        self.players[player].hands_won as f64 / self.hands as f64
+    }
+
+    /// Big blinds won per 100 hands, 0 when no hand was played.
+    pub fn bb_per_100(&self, player: usize) -> f64 {
+        if self.hands == 0 {
+            return 0.0;
+        }
+        self.players[player].net_chips as f64 / self.big_blind as f64 * 100.0 / self.hands as f64
+    }
+}
+
+/// Bots playing hand after hand, every one of them starting each hand with the same stack
+/// and the button moving one seat every hand. The deals come from an RNG seeded with `seed`,
+/// so the same bots give the same results.
+pub struct Arena {
+    players: Vec<Box<dyn Player>>,
+    stack: u64,
+    blinds: Blinds,
+    seed: u64,
+}
+
+impl Arena {
+    pub fn new(players: Vec<Box<dyn Player>>, stack: u64, blinds: Blinds, seed: u64) -> Self {
+        Arena { players, stack, blinds, seed }
+    }
+
+    pub fn run(&mut self, hands: u64) -> Result<ArenaStats, GameError> {
+        let player_count = self.players.len();
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut stats = vec![PlayerStats::default(); player_count];
+
+        for hand in 0..hands {
+            let mut deck = Deck::new();
+            deck.shuffle(&mut rng);
+            let button = (hand % player_count.max(1) as u64) as usize;
+            let mut game = Game::new(&vec![self.stack; player_count], button, self.blinds, deck)?;
+
+            while let Some(seat) = game.to_act() {
+                let action = self.players[seat].act(&GameView { game: &game, seat });
+                if game.act(action).is_err() {
+                    game.act(Action::Fold)?;
+                }
+            }
+
+            for (seat, &stack) in game.stacks().iter().enumerate() {
+                let net = stack as i64 - self.stack as i64;
+                stats[seat].net_chips += net;
+                if net > 0 {
+                    stats[seat].hands_won += 1;
+                }
+            }
+        }
+        Ok(ArenaStats { hands, big_blind: self.blinds.big, players: stats })
+    }
+}
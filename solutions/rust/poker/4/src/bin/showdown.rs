@@ -0,0 +1,102 @@
+//! Pick the winners out of a few poker hands.
+//!
+//! Hands are given as arguments, e.g. `showdown "4S 5S 7H 8D QS" "2S 4C 7S 9H 10H"`,
+//! or one per line on stdin. Pass `--json` for machine-readable output.
+
+use std::io::{self, BufRead};
+use std::process::ExitCode;
+
+use poker::{try_winning_hands, Hand};
+
+const USAGE: &str = "usage: showdown [--json] [HAND...]\n\
+    Hands are five cards such as \"4S 5S 7H 8D QS\", read one per line from stdin when none are given.";
+
+fn main() -> ExitCode {
+    let mut json = false;
+    let mut arguments = vec![];
+    for argument in std::env::args().skip(1) {
+        match argument.as_str() {
+            "--json" => json = true,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            flag if flag.starts_with("--") => {
+                eprintln!("unknown option '{}'\n{}", flag, USAGE);
+                return ExitCode::from(2);
+            }
+            _ => arguments.push(argument),
+        }
+    }
+
+    let lines = if arguments.is_empty() {
+        match io::stdin().lock().lines().collect::<Result<Vec<String>, _>>() {
+            Ok(lines) => lines,
+            Err(error) => {
+                eprintln!("cannot read stdin: {}", error);
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        arguments
+    };
+    let hands = Vec::from_iter(lines.iter().map(|line| line.trim()).filter(|line| !line.is_empty()));
+    if hands.is_empty() {
+        eprintln!("no hands given\n{}", USAGE);
+        return ExitCode::from(2);
+    }
+
+    let winners = match try_winning_hands(&hands) {
+        Ok(winners) => winners,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    // Every hand parsed fine in `try_winning_hands`.
+    let parsed = Vec::from_iter(hands.iter().map(|hand| Hand::from_string(hand)));
+
+    if json {
+        print_json(&hands, &parsed, &winners);
+    } else {
+        print_text(&hands, &parsed, &winners);
+    }
+    ExitCode::SUCCESS
+}
+
+fn print_text(hands: &[&str], parsed: &[Hand], winners: &[&str]) {
+    for winner in winners {
+        println!("winner: {}", winner);
+    }
+    for (hand, parsed) in hands.iter().zip(parsed) {
+        println!("{}: {}", hand, parsed.describe());
+    }
+}
+
+fn print_json(hands: &[&str], parsed: &[Hand], winners: &[&str]) {
+    let winners_json = Vec::from_iter(winners.iter().map(|winner| json_string(winner)));
+    let hands_json = Vec::from_iter(hands.iter().zip(parsed).map(|(hand, parsed)| format!(
+        "{{\"hand\":{},\"category\":{},\"description\":{},\"winner\":{}}}",
+        json_string(hand),
+        json_string(parsed.ranking_category().id()),
+        json_string(&parsed.describe()),
+        // Compare references: `winning_hands` hands back the very same slices.
+        winners.iter().any(|winner| std::ptr::eq(*winner, *hand)),
+    )));
+    println!("{{\"winners\":[{}],\"hands\":[{}]}}", winners_json.join(","), hands_json.join(","));
+}
+
+fn json_string(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
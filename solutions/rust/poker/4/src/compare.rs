@@ -0,0 +1,91 @@
+//! Explain why one hand beats another.
+
+use std::fmt;
+
+use crate::{Hand, Rank, RankingCategory};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Winner {
+    First,
+    Second,
+}
+
+/// What decided the comparison of two hands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Explanation {
+    /// The hands are in different categories.
+    Category { winner: Winner, winning: RankingCategory, losing: RankingCategory },
+    /// Same category, decided by the first differing rank. `position` counts the
+    /// distinct ranks in tie-break order, 0 being the main one (e.g. the pair).
+    TieBreak { winner: Winner, category: RankingCategory, position: usize, winning: Rank, losing: Rank },
+    /// Nothing separates the hands, the pot is split.
+    Split { category: RankingCategory },
+}
+
+impl Explanation {
+    pub fn winner(&self) -> Option<Winner> {
+        match *self {
+            Explanation::Category { winner, .. } | Explanation::TieBreak { winner, .. } => Some(winner),
+            Explanation::Split { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Explanation::Category { winning, losing, .. } =>
+                write!(formatter, "{} beats {}", winning, losing),
+            Explanation::TieBreak { category, position: 0, winning, losing, .. } =>
+                write!(formatter, "both have {}, {} beats {}", category, winning, losing),
+            Explanation::TieBreak { category, position, winning, losing, .. } =>
+                write!(formatter, "both have {}, {} beats {} at tie-break #{}",
+                       category, winning, losing, position + 1),
+            Explanation::Split { category } =>
+                write!(formatter, "both have the same {}, split pot", category),
+        }
+    }
+}
+
+/// Compare two hands the way `Hand::ranking` orders them, and say what decided it.
+///
+/// Categories are ordered following the hands' `Variant`, e.g. a flush beating a full house
+/// in short-deck.
+pub fn explain(first: &Hand, second: &Hand) -> Explanation {
+    let first_ranking = first.ranking();
+    let second_ranking = second.ranking();
+    let first_category = first_ranking.category;
+    let second_category = second_ranking.category;
+
+    if first_category != second_category {
+        let (winner, winning, losing) = if first_ranking.strength > second_ranking.strength {
+            (Winner::First, first_category, second_category)
+        } else {
+            (Winner::Second, second_category, first_category)
+        };
+        return Explanation::Category { winner, winning, losing };
+    }
+
+    let tie_breaks = first_ranking.values.into_iter()
+        .zip(second_ranking.values)
+        .enumerate()
+        .find(|(_i, (a, b))| a != b);
+
+    match tie_breaks {
+        Some((position, (a, b))) => {
+            let (winner, winning, losing) = if a > b {
+                (Winner::First, a, b)
+            } else {
+                (Winner::Second, b, a)
+            };
+            Explanation::TieBreak {
+                winner,
+                category: first_category,
+                position,
+                winning: Rank::from_value(winning).unwrap(),
+                losing: Rank::from_value(losing).unwrap(),
+            }
+        }
+        None => Explanation::Split { category: first_category },
+    }
+}
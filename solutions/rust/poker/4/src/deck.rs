@@ -0,0 +1,155 @@
+use std::error::Error;
+use std::fmt;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::variant::Variant;
+use crate::{Card, Rank, Suit};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeckError {
+    NotEnoughCards { needed: usize, left: usize },
+    DuplicateCard(Card),
+    CardNotInDeck(Card),
+}
+
+impl fmt::Display for DeckError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DeckError::NotEnoughCards { needed, left } =>
+                write!(formatter, "{} cards needed but only {} left in the deck", needed, left),
+            DeckError::DuplicateCard(card) =>
+                write!(formatter, "card {} is already in the deck", card),
+            DeckError::CardNotInDeck(card) =>
+                write!(formatter, "card {} is not in the deck", card),
+        }
+    }
+}
+
+impl Error for DeckError {}
+
+/// A deck of cards, dealt from the top. Its variant says how many copies of each card it may hold.
+#[derive(Debug, Clone)]
+pub struct Deck {
+    cards: Vec<Card>,
+    variant: Variant,
+}
+
+impl Deck {
+    /// The standard 52 cards, in order.
+    pub fn new() -> Self {
+        let cards = Suit::all()
+            .flat_map(|suit| Rank::all().map(move |rank| Card::new(rank, suit)))
+            .collect();
+        Deck { cards, variant: Variant::standard() }
+    }
+
+    /// A fresh deck of a variant, e.g. the 36 cards of short-deck or a six-deck shoe.
+    pub fn for_variant(variant: &Variant) -> Self {
+        Deck { cards: variant.cards(), variant: *variant }
+    }
+
+    /// A deck made of the given distinct cards, the last one being on top.
+    pub fn from_cards(cards: Vec<Card>) -> Result<Self, DeckError> {
+        Deck::from_cards_in(cards, &Variant::standard())
+    }
+
+    /// A deck of `variant` made of the given cards, each one at most as many times as
+    /// the variant has decks.
+    pub fn from_cards_in(cards: Vec<Card>, variant: &Variant) -> Result<Self, DeckError> {
+        for (i, card) in cards.iter().enumerate() {
+            if card.rank < variant.lowest_rank {
+                return Err(DeckError::CardNotInDeck(*card));
+            }
+            if cards[..i].iter().filter(|&c| c == card).count() >= variant.decks as usize {
+                return Err(DeckError::DuplicateCard(*card));
+            }
+        }
+        Ok(Deck { cards, variant: *variant })
+    }
+
+    pub fn variant(&self) -> Variant {
+        self.variant
+    }
+
+    /// Shuffle with a caller-supplied RNG, so a seeded one gives reproducible games.
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// The cards not dealt yet, the last one being on top.
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    pub fn contains(&self, card: &Card) -> bool {
+        self.cards.contains(card)
+    }
+
+    pub fn deal(&mut self) -> Result<Card, DeckError> {
+        self.cards.pop().ok_or(DeckError::NotEnoughCards { needed: 1, left: 0 })
+    }
+
+    pub fn deal_many(&mut self, count: usize) -> Result<Vec<Card>, DeckError> {
+        self.ensure_left(count)?;
+        let mut dealt = self.cards.split_off(self.cards.len() - count);
+        dealt.reverse();
+        Ok(dealt)
+    }
+
+    /// Deal `cards_each` cards to each of `players`, one card at a time around the table.
+    pub fn deal_to(&mut self, players: usize, cards_each: usize) -> Result<Vec<Vec<Card>>, DeckError> {
+        self.ensure_left(players * cards_each)?;
+        let mut hands = vec![Vec::with_capacity(cards_each); players];
+        for _ in 0..cards_each {
+            for hand in hands.iter_mut() {
+                hand.push(self.deal()?);
+            }
+        }
+        Ok(hands)
+    }
+
+    /// Discard the top card face down.
+    pub fn burn(&mut self) -> Result<Card, DeckError> {
+        self.deal()
+    }
+
+    /// Take a known card out of the deck, e.g. the hole cards of a player.
+    pub fn remove(&mut self, card: &Card) -> Result<Card, DeckError> {
+        let position = self.cards.iter().position(|c| c == card)
+            .ok_or(DeckError::CardNotInDeck(*card))?;
+        Ok(self.cards.remove(position))
+    }
+
+    /// Put a card back at the bottom of the deck, refusing one which is already in
+    /// as many times as the variant has decks.
+    pub fn return_card(&mut self, card: Card) -> Result<(), DeckError> {
+        if self.cards.iter().filter(|&&c| c == card).count() >= self.variant.decks as usize {
+            return Err(DeckError::DuplicateCard(card));
+        }
+        self.cards.insert(0, card);
+        Ok(())
+    }
+
+    fn ensure_left(&self, needed: usize) -> Result<(), DeckError> {
+        if needed > self.cards.len() {
+            return Err(DeckError::NotEnoughCards { needed, left: self.cards.len() });
+        }
+        Ok(())
+    }
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Deck::new()
+    }
+}
@@ -0,0 +1,148 @@
+//! Human-readable hand descriptions, such as "Full house, Kings over Sevens".
+
+use crate::{Hand, Rank, RankingCategory};
+
+/// Languages `Hand::describe_in` can write in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    #[default]
+    English,
+    French,
+}
+
+/// The ranks which matter when describing a hand, read off its sorted cards.
+enum Shape {
+    HighCard { high: Rank },
+    OnePair { pair: Rank, kicker: Rank },
+    TwoPair { high: Rank, low: Rank, kicker: Rank },
+    Trips { trips: Rank },
+    Straight { high: Rank },
+    Flush { high: Rank },
+    Full { trips: Rank, pair: Rank },
+    Quads { quads: Rank },
+    StraightFlush { high: Rank },
+    FiveOfAKind { rank: Rank },
+}
+
+impl Hand {
+    /// Describe the hand in English, e.g. "Two pair, Aces and Fours, Queen kicker".
+    pub fn describe(&self) -> String {
+        self.describe_in(Language::English)
+    }
+
+    pub fn describe_in(&self, language: Language) -> String {
+        let shape = self.shape();
+        match language {
+            Language::English => describe_in_english(&shape, self.ace_low),
+            Language::French => describe_in_french(&shape, self.ace_low),
+        }
+    }
+
+    fn shape(&self) -> Shape {
+        // Cards are sorted by group size then rank, so the deciding ranks sit at the end.
+        let rank_at = |i: usize| self.sorted_cards[i].rank;
+        match self.ranking_category() {
+            RankingCategory::HighestCard => Shape::HighCard { high: rank_at(4) },
+            RankingCategory::OnePair => Shape::OnePair { pair: rank_at(4), kicker: rank_at(2) },
+            RankingCategory::TwoPair => Shape::TwoPair { high: rank_at(4), low: rank_at(2), kicker: rank_at(0) },
+            RankingCategory::Trips => Shape::Trips { trips: rank_at(4) },
+            RankingCategory::Straight => Shape::Straight { high: rank_at(4) },
+            // With several decks a flush may be paired, so its top card is not always last.
+            RankingCategory::Flush => Shape::Flush { high: self.sorted_cards.iter().map(|c| c.rank).max().unwrap() },
+            RankingCategory::Full => Shape::Full { trips: rank_at(4), pair: rank_at(0) },
+            RankingCategory::Quads => Shape::Quads { quads: rank_at(4) },
+            RankingCategory::StraightFlush => Shape::StraightFlush { high: rank_at(4) },
+            RankingCategory::FiveOfAKind => Shape::FiveOfAKind { rank: rank_at(4) },
+        }
+    }
+}
+
+fn english_name(rank: Rank) -> &'static str {
+    [
+        "Two", "Three", "Four", "Five", "Six", "Seven", "Eight",
+        "Nine", "Ten", "Jack", "Queen", "King", "Ace",
+    ][rank as usize - 2]
+}
+
+fn english_plural(rank: Rank) -> &'static str {
+    [
+        "Twos", "Threes", "Fours", "Fives", "Sixes", "Sevens", "Eights",
+        "Nines", "Tens", "Jacks", "Queens", "Kings", "Aces",
+    ][rank as usize - 2]
+}
+
+fn describe_in_english(shape: &Shape, ace_low: bool) -> String {
+    let name = english_name;
+    let plural = english_plural;
+    let wheel = if ace_low { " (wheel)" } else { "" };
+
+    match *shape {
+        Shape::HighCard { high } =>
+            format!("High card, {}", name(high)),
+        Shape::OnePair { pair, kicker } =>
+            format!("One pair, {}, {} kicker", plural(pair), name(kicker)),
+        Shape::TwoPair { high, low, kicker } =>
+            format!("Two pair, {} and {}, {} kicker", plural(high), plural(low), name(kicker)),
+        Shape::Trips { trips } =>
+            format!("Three of a kind, {}", plural(trips)),
+        Shape::Straight { high } =>
+            format!("Straight, {} high{}", name(high), wheel),
+        Shape::Flush { high } =>
+            format!("Flush, {} high", name(high)),
+        Shape::Full { trips, pair } =>
+            format!("Full house, {} over {}", plural(trips), plural(pair)),
+        Shape::Quads { quads } =>
+            format!("Four of a kind, {}", plural(quads)),
+        Shape::StraightFlush { high: Rank::Ace } =>
+            "Royal flush".to_string(),
+        Shape::StraightFlush { high } =>
+            format!("Straight flush, {} high{}", name(high), wheel),
+        Shape::FiveOfAKind { rank } =>
+            format!("Five of a kind, {}", plural(rank)),
+    }
+}
+
+fn french_name(rank: Rank) -> &'static str {
+    [
+        "Deux", "Trois", "Quatre", "Cinq", "Six", "Sept", "Huit",
+        "Neuf", "Dix", "Valet", "Dame", "Roi", "As",
+    ][rank as usize - 2]
+}
+
+fn french_plural(rank: Rank) -> &'static str {
+    [
+        "Deux", "Trois", "Quatre", "Cinq", "Six", "Sept", "Huit",
+        "Neuf", "Dix", "Valets", "Dames", "Rois", "As",
+    ][rank as usize - 2]
+}
+
+fn describe_in_french(shape: &Shape, ace_low: bool) -> String {
+    let name = french_name;
+    let plural = french_plural;
+    let wheel = if ace_low { " (roue)" } else { "" };
+
+    match *shape {
+        Shape::HighCard { high } =>
+            format!("Carte haute, {}", name(high)),
+        Shape::OnePair { pair, kicker } =>
+            format!("Paire de {}, kicker {}", plural(pair), name(kicker)),
+        Shape::TwoPair { high, low, kicker } =>
+            format!("Double paire, {} et {}, kicker {}", plural(high), plural(low), name(kicker)),
+        Shape::Trips { trips } =>
+            format!("Brelan de {}", plural(trips)),
+        Shape::Straight { high } =>
+            format!("Quinte, hauteur {}{}", name(high), wheel),
+        Shape::Flush { high } =>
+            format!("Couleur, hauteur {}", name(high)),
+        Shape::Full { trips, pair } =>
+            format!("Full, {} par les {}", plural(trips), plural(pair)),
+        Shape::Quads { quads } =>
+            format!("Carré de {}", plural(quads)),
+        Shape::StraightFlush { high: Rank::Ace } =>
+            "Quinte flush royale".to_string(),
+        Shape::StraightFlush { high } =>
+            format!("Quinte flush, hauteur {}{}", name(high), wheel),
+        Shape::FiveOfAKind { rank } =>
+            format!("Quinte de {}", plural(rank)),
+    }
+}
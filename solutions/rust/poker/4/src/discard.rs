@@ -0,0 +1,123 @@
+//! Five-card draw: which cards to hold and which to throw away.
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::deck::{Deck, DeckError};
+use crate::equity::EquityMethod;
+use crate::evaluator::{category_of, evaluate};
+use crate::variant::Variant;
+use crate::{Card, Hand, RankingCategory};
+
+/// One way to play a hand: the cards kept, the ones thrown away and what the draw is worth.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HoldOption {
+    pub hold: Vec<Card>,
+    pub discard: Vec<Card>,
+    /// The chance of ending up with each category, indexed by `RankingCategory as usize`.
+    pub category_odds: [f64; 10],
+    /// The average payout of the hand after the draw.
+    pub expected_payout: f64,
+}
+
+impl HoldOption {
+    pub fn chance_of(&self, category: RankingCategory) -> f64 {
+        self.category_odds[category as usize]
+    }
+}
+
+/// Evaluate the 32 ways to hold some of the cards of `hand`, the replacements being drawn
+/// from the rest of the hand's deck and each final hand paying `payout` of its category.
+/// The best option comes first.
+///
+/// Fails when the hand holds cards its deck does not have, e.g. the same card twice with
+/// a single deck.
+pub fn advise_discard<F>(hand: &Hand, payout: F, method: EquityMethod) -> Result<Vec<HoldOption>, DeckError>
+    where F: Fn(RankingCategory) -> f64
+{
+    let variant = hand.variant;
+    let mut deck = Deck::for_variant(&variant);
+    for card in hand.cards() {
+        deck.remove(card)?;
+    }
+    let remaining = deck.cards();
+    let mut rng = match method {
+        EquityMethod::MonteCarlo { seed, .. } => Some(StdRng::seed_from_u64(seed)),
+        EquityMethod::Exhaustive => None,
+    };
+
+    let mut options = Vec::from_iter((0..1_u32 << 5).map(|mask| {
+        let held = |i: &usize| mask & (1 << i) != 0;
+        let hold = Vec::from_iter((0..5).filter(held).map(|i| hand.cards()[i]));
+        let discard = Vec::from_iter((0..5).filter(|i| !held(i)).map(|i| hand.cards()[i]));
+
+        let counts = match (method, rng.as_mut()) {
+            (EquityMethod::MonteCarlo { iterations, .. }, Some(rng)) if !discard.is_empty() && iterations > 0 =>
+                sampled_counts(&hold, remaining, discard.len(), iterations, &variant, rng),
+            _ => exact_counts(&hold, remaining, discard.len(), &variant),
+        };
+        let draws: u64 = counts.iter().sum();
+        let category_odds = counts.map(|count| count as f64 / draws as f64);
+        let expected_payout = RankingCategory::ALL.iter()
+            .map(|&category| category_odds[category as usize] * payout(category))
+            .sum();
+        HoldOption { hold, discard, category_odds, expected_payout }
+    }));
+    options.sort_by(|a, b| b.expected_payout.total_cmp(&a.expected_payout));
+    Ok(options)
+}
+
+fn category(cards: &[Card], variant: &Variant) -> RankingCategory {
+    if *variant == Variant::standard() {
+        category_of(evaluate(cards.iter().collect()))
+    } else {
+        Hand::from_cards_in(cards.to_vec(), *variant).ranking_category()
+    }
+}
+
+/// How many of the ways to draw `count` of the `remaining` cards end in each category.
+fn exact_counts(kept: &[Card], remaining: &[Card], count: usize, variant: &Variant) -> [u64; 10] {
+    let mut counts = [0; 10];
+    let mut cards = kept.to_vec();
+    for_each_draw(&mut cards, remaining, count, &mut |cards| {
+        counts[category(cards, variant) as usize] += 1;
+    });
+    counts
+}
+
+fn for_each_draw<F>(cards: &mut Vec<Card>, remaining: &[Card], count: usize, visit: &mut F)
+    where F: FnMut(&[Card])
+{
+    if count == 0 {
+        visit(cards);
+        return;
+    }
+    for (i, &card) in remaining[..=remaining.len() - count].iter().enumerate() {
+        cards.push(card);
+        for_each_draw(cards, &remaining[i + 1..], count - 1, visit);
+        cards.pop();
+    }
+}
+
+/// How many of `iterations` random draws of `count` of the `remaining` cards end in each
+/// category.
+fn sampled_counts(
+    kept: &[Card],
+    remaining: &[Card],
+    count: usize,
+    iterations: usize,
+    variant: &Variant,
+    rng: &mut StdRng,
+) -> [u64; 10] {
+    let mut counts = [0; 10];
+    let mut deck = remaining.to_vec();
+    let mut cards = kept.to_vec();
+    for _ in 0..iterations {
+        let (drawn, _rest) = deck.partial_shuffle(rng, count);
+        cards.truncate(kept.len());
+        cards.extend_from_slice(drawn);
+        counts[category(&cards, variant) as usize] += 1;
+    }
+    counts
+}
@@ -0,0 +1,155 @@
+use std::error::Error;
+use std::fmt;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::deck::Deck;
+use crate::holdem::best_hand_from;
+use crate::{for_each_combination, Card};
+
+/// How the missing board cards are dealt out.
+#[derive(Debug, Clone, Copy)]
+pub enum EquityMethod {
+    /// Every possible run-out of the remaining cards.
+    Exhaustive,
+    /// `iterations` random run-outs drawn from an RNG seeded with `seed`.
+    MonteCarlo { iterations: usize, seed: u64 },
+}
+
+/// Win, tie and loss probabilities of one player, plus its expected share of the pot.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Equity {
+    pub win: f64,
+    pub tie: f64,
+    pub loss: f64,
+    pub equity: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EquityError {
+    NotEnoughPlayers,
+    TooManyPlayers(usize),
+    TooManyBoardCards(usize),
+    DuplicateCard(Card),
+}
+
+impl fmt::Display for EquityError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EquityError::NotEnoughPlayers =>
+                write!(formatter, "at least two players are needed"),
+            EquityError::TooManyPlayers(count) =>
+                write!(formatter, "{} players leave no cards for the board", count),
+            EquityError::TooManyBoardCards(count) =>
+                write!(formatter, "a board has at most 5 cards, got {}", count),
+            EquityError::DuplicateCard(card) =>
+                write!(formatter, "card {} is dealt more than once", card),
+        }
+    }
+}
+
+impl Error for EquityError {}
+
+/// Compute each Hold'em player's equity from their known hole cards and a partial board.
+pub fn equity(
+    players: &[[Card; 2]],
+    board: &[Card],
+    method: EquityMethod,
+) -> Result<Vec<Equity>, EquityError> {
+    if players.len() < 2 {
+        return Err(EquityError::NotEnoughPlayers);
+    }
+    // Hole cards and a full board must fit in one deck.
+    if players.len() * 2 + 5 > 52 {
+        return Err(EquityError::TooManyPlayers(players.len()));
+    }
+    if board.len() > 5 {
+        return Err(EquityError::TooManyBoardCards(board.len()));
+    }
+    let known_cards = Vec::from_iter(players.iter().flatten().chain(board));
+    for (i, card) in known_cards.iter().enumerate() {
+        if known_cards[..i].contains(card) {
+            return Err(EquityError::DuplicateCard(**card));
+        }
+    }
+
+    let mut deck = Deck::new();
+    for card in known_cards {
+        deck.remove(card).unwrap();
+    }
+    let remaining_cards = deck.cards().to_vec();
+    let missing = 5 - board.len();
+    let mut tally = Tally::new(players.len());
+
+    match method {
+        EquityMethod::Exhaustive => {
+            for_each_combination(&remaining_cards, missing, &mut |run_out| {
+                tally.record(players, board, run_out);
+            });
+        }
+        EquityMethod::MonteCarlo { iterations, seed } => {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut deck = remaining_cards;
+            for _ in 0..iterations {
+                let (run_out, _rest) = deck.partial_shuffle(&mut rng, missing);
+                tally.record(players, board, run_out);
+            }
+        }
+    }
+    Ok(tally.into_equities())
+}
+
+/// Outcomes counted over all the run-outs played so far.
+struct Tally {
+    wins: Vec<u64>,
+    ties: Vec<u64>,
+    shares: Vec<f64>,
+    run_outs: u64,
+}
+
+impl Tally {
+    fn new(player_count: usize) -> Self {
+        Tally {
+            wins: vec![0; player_count],
+            ties: vec![0; player_count],
+            shares: vec![0.0; player_count],
+            run_outs: 0,
+        }
+    }
+
+    fn record(&mut self, players: &[[Card; 2]], board: &[Card], run_out: &[Card]) {
+        let rankings = Vec::from_iter(players.iter().map(|hole_cards| {
+            let cards = Vec::from_iter(hole_cards.iter().chain(board).chain(run_out).copied());
+            best_hand_from(&cards).unwrap().ranking
+        }));
+        let top_ranking = rankings.iter().max().unwrap();
+        let winners = Vec::from_iter((0..players.len()).filter(|&i| rankings[i] == *top_ranking));
+
+        for &i in winners.iter() {
+            if winners.len() == 1 {
+                self.wins[i] += 1;
+            } else {
+                self.ties[i] += 1;
+            }
+            self.shares[i] += 1.0 / winners.len() as f64;
+        }
+        self.run_outs += 1;
+    }
+
+    fn into_equities(self) -> Vec<Equity> {
+        if self.run_outs == 0 {
+            return vec![Equity::default(); self.wins.len()];
+        }
+        let total = self.run_outs as f64;
+
+        (0..self.wins.len())
+            .map(|i| {
+                let win = self.wins[i] as f64 / total;
+                let tie = self.ties[i] as f64 / total;
+                Equity { win, tie, loss: 1.0 - win - tie, equity: self.shares[i] / total }
+            })
+            .collect()
+    }
+}
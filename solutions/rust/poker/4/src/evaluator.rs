@@ -0,0 +1,228 @@
+//! Allocation-free hand evaluation over bit-packed cards.
+//!
+//! A card is a single bit in a `u64`: 16 bits per suit, the rank giving the bit
+//! position inside its suit. Counting ranks then boils down to ANDing the four
+//! suit masks together, and straights are found in a table precomputed at compile time.
+
+use std::str::FromStr;
+
+use crate::{parse_rank_and_suit, Card, PokerParseError, Rank, RankingCategory, Suit};
+use crate::RankingCategory::*;
+
+const RANKS_MASK: u16 = 0x7FFC;
+
+/// A card packed as `suit * 16 + rank`, ranks going from 2 to 14 (Ace).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PackedCard(u8);
+
+impl PackedCard {
+    pub fn new(rank: Rank, suit: Suit) -> Self {
+        PackedCard(suit as u8 * 16 + rank.value())
+    }
+
+    pub fn rank(self) -> Rank {
+        Rank::from_value(self.0 % 16).unwrap()
+    }
+
+    pub fn suit(self) -> Suit {
+        Suit::ALL[(self.0 / 16) as usize]
+    }
+}
+
+impl From<Card> for PackedCard {
+    fn from(card: Card) -> Self {
+        PackedCard::new(card.rank, card.suit)
+    }
+}
+
+impl From<&Card> for PackedCard {
+    fn from(card: &Card) -> Self {
+        PackedCard::new(card.rank, card.suit)
+    }
+}
+
+impl FromStr for PackedCard {
+    type Err = PokerParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (rank, suit) = parse_rank_and_suit(input)?;
+        Ok(PackedCard::new(rank, suit))
+    }
+}
+
+/// A set of distinct cards, one bit per card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct CardSet(u64);
+
+impl CardSet {
+    pub fn new() -> Self {
+        CardSet(0)
+    }
+
+    /// Add `card` to the set, returning `false` if it was already in.
+    pub fn insert(&mut self, card: PackedCard) -> bool {
+        let bit = 1_u64 << card.0;
+        let added = self.0 & bit == 0;
+        self.0 |= bit;
+        added
+    }
+
+    pub fn contains(self, card: PackedCard) -> bool {
+        self.0 & (1_u64 << card.0) != 0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: CardSet) -> CardSet {
+        CardSet(self.0 | other.0)
+    }
+
+    fn suit_masks(self) -> [u16; 4] {
+        [0, 1, 2, 3].map(|s| (self.0 >> (s * 16)) as u16 & RANKS_MASK)
+    }
+}
+
+impl FromIterator<PackedCard> for CardSet {
+    fn from_iter<I: IntoIterator<Item=PackedCard>>(cards: I) -> Self {
+        let mut set = CardSet::new();
+        for card in cards {
+            set.insert(card);
+        }
+        set
+    }
+}
+
+impl<'a> FromIterator<&'a Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item=&'a Card>>(cards: I) -> Self {
+        cards.into_iter().map(PackedCard::from).collect()
+    }
+}
+
+impl FromStr for CardSet {
+    type Err = PokerParseError;
+
+    /// Parse whitespace separated cards without any heap allocation on success.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut set = CardSet::new();
+        for token in input.split_whitespace() {
+            let offset = token.as_ptr() as usize - input.as_ptr() as usize;
+            let card: PackedCard = token.parse().map_err(|e: PokerParseError| e.shifted(offset))?;
+            if !set.insert(card) {
+                return Err(PokerParseError::DuplicateCard { token: token.to_string(), offset });
+            }
+        }
+        if set.is_empty() {
+            return Err(PokerParseError::EmptyInput);
+        }
+        Ok(set)
+    }
+}
+
+/// Highest rank of the straight found in a rank mask, 0 if there is none.
+/// Indexed by the rank mask with the Ace also set on bit 1, so the wheel is found.
+static STRAIGHT_HIGH: [u8; 1 << 15] = straight_high_table();
+
+const fn straight_high_table() -> [u8; 1 << 15] {
+    let mut table = [0_u8; 1 << 15];
+    let mut mask = 0;
+    while mask < (1 << 15) {
+        let mut high = 14;
+        while high >= 5 {
+            let run = 0b11111 << (high - 4);
+            if mask & run == run {
+                table[mask] = high as u8;
+                break;
+            }
+            high -= 1;
+        }
+        mask += 1;
+    }
+    table
+}
+
+fn straight_high(rank_mask: u16) -> u8 {
+    let with_low_ace = rank_mask | ((rank_mask >> 14) & 1) << 1;
+    STRAIGHT_HIGH[with_low_ace as usize]
+}
+
+fn highest_rank(rank_mask: u16) -> u16 {
+    15 - rank_mask.leading_zeros() as u16
+}
+
+/// Push the `count` highest ranks of `rank_mask` into `score`, four bits each.
+fn push_highest(mut score: u32, mut rank_mask: u16, count: usize) -> u32 {
+    for _ in 0..count {
+        let rank = highest_rank(rank_mask);
+        score = (score << 4) | rank as u32;
+        rank_mask &= !(1 << rank);
+    }
+    score
+}
+
+fn score(category: RankingCategory, kickers: u32, kicker_count: usize) -> u32 {
+    ((category as u32) << 20) | (kickers << (4 * (5 - kicker_count)))
+}
+
+/// Score the best five-card hand found in `cards` (five to seven of them).
+///
+/// The higher the score the better the hand: the category sits above 20 bits of
+/// ranks, four bits each, in the order they break ties.
+pub fn evaluate(cards: CardSet) -> u32 {
+    let [s0, s1, s2, s3] = cards.suit_masks();
+    let all = s0 | s1 | s2 | s3;
+    let quads = s0 & s1 & s2 & s3;
+    let trips = ((s0 & s1 & s2) | (s0 & s1 & s3) | (s0 & s2 & s3) | (s1 & s2 & s3)) & !quads;
+    let pairs = ((s0 & s1) | (s0 & s2) | (s0 & s3) | (s1 & s2) | (s1 & s3) | (s2 & s3))
+        & !quads & !trips;
+
+    let flush = [s0, s1, s2, s3].into_iter().find(|s| s.count_ones() >= 5);
+    if let Some(suit_mask) = flush {
+        let high = straight_high(suit_mask);
+        if high > 0 {
+            return score(StraightFlush, high as u32, 1);
+        }
+    }
+    if quads != 0 {
+        let quad = highest_rank(quads);
+        return score(Quads, push_highest(quad as u32, all & !(1 << quad), 1), 2);
+    }
+    if trips != 0 {
+        let trip = highest_rank(trips);
+        let pair_candidates = (trips & !(1 << trip)) | pairs;
+        if pair_candidates != 0 {
+            return score(Full, (trip as u32) << 4 | highest_rank(pair_candidates) as u32, 2);
+        }
+    }
+    if let Some(suit_mask) = flush {
+        return score(Flush, push_highest(0, suit_mask, 5), 5);
+    }
+    let high = straight_high(all);
+    if high > 0 {
+        return score(Straight, high as u32, 1);
+    }
+    if trips != 0 {
+        let trip = highest_rank(trips);
+        return score(Trips, push_highest(trip as u32, all & !(1 << trip), 2), 3);
+    }
+    if pairs.count_ones() >= 2 {
+        let top_pairs = push_highest(0, pairs, 2);
+        let kickers = all & !(1 << (top_pairs >> 4)) & !(1 << (top_pairs & 0xF));
+        return score(TwoPair, push_highest(top_pairs, kickers, 1), 3);
+    }
+    if pairs != 0 {
+        let pair = highest_rank(pairs);
+        return score(OnePair, push_highest(pair as u32, all & !(1 << pair), 3), 4);
+    }
+    score(HighestCard, push_highest(0, all, 5), 5)
+}
+
+/// The category encoded in a score returned by `evaluate`.
+pub fn category_of(score: u32) -> RankingCategory {
+    RankingCategory::ALL[(score >> 20) as usize]
+}
@@ -0,0 +1,495 @@
+//! A No-Limit Hold'em hand, from the blinds to the showdown.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::deck::{Deck, DeckError};
+use crate::holdem::best_hand_from;
+use crate::settlement::{settle, Stake};
+use crate::{Card, RankingCategory};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Street {
+    Preflop,
+    Flop,
+    Turn,
+    River,
+    Showdown,
+}
+
+/// What a player can do when it is their turn.
+///
+/// `Bet` and `Raise` give the total the player's bet goes to on this street.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Fold,
+    Check,
+    Call,
+    Bet(u64),
+    Raise(u64),
+    AllIn,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Blinds {
+    pub small: u64,
+    pub big: u64,
+}
+
+/// Everything that happens during a hand, in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameEvent {
+    BlindPosted { seat: usize, amount: u64 },
+    HoleCardsDealt { seat: usize, cards: [Card; 2] },
+    /// `put_in` is how many chips the action added to the pot.
+    Acted { seat: usize, action: Action, put_in: u64, all_in: bool },
+    BoardDealt { street: Street, cards: Vec<Card> },
+    ShowedDown { seat: usize, cards: Vec<Card>, category: RankingCategory },
+    PotWon { seat: usize, amount: u64 },
+    HandFinished,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameError {
+    NotEnoughPlayers,
+    TooManyPlayers { max: usize },
+    HandOver,
+    CannotCheck,
+    NothingToCall,
+    BetNotAllowed,
+    RaiseNotAllowed,
+    BetTooSmall { min: u64 },
+    RaiseTooSmall { min: u64 },
+    NotEnoughChips { max: u64 },
+    Deck(DeckError),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GameError::NotEnoughPlayers => write!(formatter, "a hand needs at least two players with chips"),
+            GameError::TooManyPlayers { max } => write!(formatter, "at most {} players can be dealt in", max),
+            GameError::HandOver => write!(formatter, "the hand is over"),
+            GameError::CannotCheck => write!(formatter, "cannot check facing a bet"),
+            GameError::NothingToCall => write!(formatter, "there is no bet to call"),
+            GameError::BetNotAllowed => write!(formatter, "there is already a bet, raise instead"),
+            GameError::RaiseNotAllowed => write!(formatter, "raising is not allowed"),
+            GameError::BetTooSmall { min } => write!(formatter, "a bet must be at least {}", min),
+            GameError::RaiseTooSmall { min } => write!(formatter, "a raise must go to at least {}", min),
+            GameError::NotEnoughChips { max } => write!(formatter, "cannot bet more than {}", max),
+            GameError::Deck(error) => write!(formatter, "{}", error),
+        }
+    }
+}
+
+impl Error for GameError {}
+
+impl From<DeckError> for GameError {
+    fn from(error: DeckError) -> Self {
+        GameError::Deck(error)
+    }
+}
+
+/// The actions open to the player to act, amounts being street totals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LegalActions {
+    pub can_check: bool,
+    /// Chips needed to call, capped by the player's stack.
+    pub call_amount: u64,
+    pub min_bet: Option<u64>,
+    pub min_raise_to: Option<u64>,
+    /// The street total the player reaches by going all-in.
+    pub all_in_to: u64,
+}
+
+#[derive(Debug, Clone)]
+struct Seat {
+    stack: u64,
+    hole_cards: [Card; 2],
+    street_bet: u64,
+    contribution: u64,
+    folded: bool,
+    all_in: bool,
+    /// Acted since the last full raise, and so not allowed to re-raise.
+    acted: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    seats: Vec<Seat>,
+    starting_stacks: Vec<u64>,
+    button: usize,
+    blinds: Blinds,
+    deck: Deck,
+    board: Vec<Card>,
+    street: Street,
+    to_act: Option<usize>,
+    current_bet: u64,
+    last_raise_size: u64,
+    events: Vec<GameEvent>,
+}
+
+impl Game {
+    /// Post the blinds and deal the hole cards of a new hand, seat `i` starting with `stacks[i]`.
+    pub fn new(stacks: &[u64], button: usize, blinds: Blinds, mut deck: Deck) -> Result<Game, GameError> {
+        if stacks.len() < 2 || stacks.contains(&0) || button >= stacks.len() {
+            return Err(GameError::NotEnoughPlayers);
+        }
+        let player_count = stacks.len();
+
+        let mut hole_cards = vec![vec![]; player_count];
+        for _ in 0..2 {
+            for n in 1..=player_count {
+                hole_cards[(button + n) % player_count].push(deck.deal()?);
+            }
+        }
+        let seats = Vec::from_iter(stacks.iter().zip(hole_cards).map(|(&stack, cards)| Seat {
+            stack,
+            hole_cards: [cards[0], cards[1]],
+            street_bet: 0,
+            contribution: 0,
+            folded: false,
+            all_in: false,
+            acted: false,
+        }));
+
+        let mut game = Game {
+            seats,
+            starting_stacks: stacks.to_vec(),
+            button,
+            blinds,
+            deck,
+            board: vec![],
+            street: Street::Preflop,
+            to_act: None,
+            current_bet: 0,
+            last_raise_size: blinds.big,
+            events: vec![],
+        };
+
+        let (small_blind_seat, big_blind_seat) = if player_count == 2 {
+            (button, game.next_seat(button))
+        } else {
+            let small_blind_seat = game.next_seat(button);
+            (small_blind_seat, game.next_seat(small_blind_seat))
+        };
+        for (seat, amount) in [(small_blind_seat, blinds.small), (big_blind_seat, blinds.big)] {
+            let put_in = game.put_in(seat, amount);
+            game.events.push(GameEvent::BlindPosted { seat, amount: put_in });
+        }
+        game.current_bet = blinds.big;
+        for seat in 0..player_count {
+            let cards = game.seats[seat].hole_cards;
+            game.events.push(GameEvent::HoleCardsDealt { seat, cards });
+        }
+
+        game.to_act = game.find_next_to_act(big_blind_seat);
+        if game.to_act.is_none() {
+            game.finish_street()?;
+        }
+        Ok(game)
+    }
+
+    pub fn player_count(&self) -> usize {
+        self.seats.len()
+    }
+
+    pub fn button(&self) -> usize {
+        self.button
+    }
+
+    pub fn blinds(&self) -> Blinds {
+        self.blinds
+    }
+
+    pub fn street(&self) -> Street {
+        self.street
+    }
+
+    pub fn board(&self) -> &[Card] {
+        &self.board
+    }
+
+    /// The seat whose turn it is, `None` once the hand is over.
+    pub fn to_act(&self) -> Option<usize> {
+        self.to_act
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.events.last() == Some(&GameEvent::HandFinished)
+    }
+
+    pub fn events(&self) -> &[GameEvent] {
+        &self.events
+    }
+
+    /// The stacks before the blinds were posted.
+    pub fn starting_stacks(&self) -> &[u64] {
+        &self.starting_stacks
+    }
+
+    pub fn stack(&self, seat: usize) -> u64 {
+        self.seats[seat].stack
+    }
+
+    pub fn stacks(&self) -> Vec<u64> {
+        self.seats.iter().map(|s| s.stack).collect()
+    }
+
+    pub fn hole_cards(&self, seat: usize) -> [Card; 2] {
+        self.seats[seat].hole_cards
+    }
+
+    pub fn street_bet(&self, seat: usize) -> u64 {
+        self.seats[seat].street_bet
+    }
+
+    pub fn contribution(&self, seat: usize) -> u64 {
+        self.seats[seat].contribution
+    }
+
+    pub fn has_folded(&self, seat: usize) -> bool {
+        self.seats[seat].folded
+    }
+
+    pub fn is_all_in(&self, seat: usize) -> bool {
+        self.seats[seat].all_in
+    }
+
+    pub fn current_bet(&self) -> u64 {
+        self.current_bet
+    }
+
+    pub fn pot(&self) -> u64 {
+        self.seats.iter().map(|s| s.contribution).sum()
+    }
+
+    pub fn legal_actions(&self) -> Option<LegalActions> {
+        let seat = &self.seats[self.to_act?];
+        let all_in_to = seat.street_bet + seat.stack;
+        let can_raise = !seat.acted && all_in_to > self.current_bet;
+
+        Some(LegalActions {
+            can_check: seat.street_bet == self.current_bet,
+            call_amount: (self.current_bet - seat.street_bet).min(seat.stack),
+            min_bet: (self.current_bet == 0 && all_in_to >= self.blinds.big).then_some(self.blinds.big),
+            min_raise_to: Some(self.current_bet + self.last_raise_size)
+                .filter(|&to| self.current_bet > 0 && can_raise && to <= all_in_to),
+            all_in_to,
+        })
+    }
+
+    /// Play `action` for the player to act, returning the events it led to.
+    pub fn act(&mut self, action: Action) -> Result<&[GameEvent], GameError> {
+        let seat = self.to_act.ok_or(GameError::HandOver)?;
+        let legal = self.legal_actions().unwrap();
+        let first_new_event = self.events.len();
+
+        let put_in = match action {
+            Action::Fold => {
+                self.seats[seat].folded = true;
+                0
+            }
+            Action::Check => {
+                if !legal.can_check {
+                    return Err(GameError::CannotCheck);
+                }
+                0
+            }
+            Action::Call => {
+                if legal.can_check {
+                    return Err(GameError::NothingToCall);
+                }
+                self.put_in(seat, legal.call_amount)
+            }
+            Action::Bet(to) => {
+                if self.current_bet > 0 {
+                    return Err(GameError::BetNotAllowed);
+                }
+                let min = legal.min_bet.ok_or(GameError::BetTooSmall { min: self.blinds.big })?;
+                self.raise_to(seat, to, min, legal.all_in_to)?
+            }
+            Action::Raise(to) => {
+                if self.current_bet == 0 || self.seats[seat].acted {
+                    return Err(GameError::RaiseNotAllowed);
+                }
+                let min = self.current_bet + self.last_raise_size;
+                self.raise_to(seat, to, min, legal.all_in_to)?
+            }
+            Action::AllIn => {
+                if legal.all_in_to > self.current_bet && self.seats[seat].acted {
+                    return Err(GameError::RaiseNotAllowed);
+                }
+                let stack = self.seats[seat].stack;
+                self.raise_by_all_in(seat, legal.all_in_to);
+                self.put_in(seat, stack)
+            }
+        };
+        self.seats[seat].acted = true;
+        let all_in = self.seats[seat].all_in;
+        self.events.push(GameEvent::Acted { seat, action, put_in, all_in });
+
+        self.advance(seat)?;
+        Ok(&self.events[first_new_event..])
+    }
+
+    fn raise_to(&mut self, seat: usize, to: u64, min: u64, all_in_to: u64) -> Result<u64, GameError> {
+        if to > all_in_to {
+            return Err(GameError::NotEnoughChips { max: all_in_to });
+        }
+        if to < min && to < all_in_to {
+            return Err(if self.current_bet == 0 {
+                GameError::BetTooSmall { min }
+            } else {
+                GameError::RaiseTooSmall { min }
+            });
+        }
+        self.raise_by_all_in(seat, to);
+        let street_bet = self.seats[seat].street_bet;
+        Ok(self.put_in(seat, to - street_bet))
+    }
+
+    /// Update the bet to `to`, a full raise re-opening the betting for everyone else.
+    fn raise_by_all_in(&mut self, seat: usize, to: u64) {
+        if to <= self.current_bet {
+            return;
+        }
+        let raise_size = to - self.current_bet;
+        if raise_size >= self.last_raise_size {
+            self.last_raise_size = raise_size;
+            for (i, other) in self.seats.iter_mut().enumerate() {
+                if i != seat {
+                    other.acted = false;
+                }
+            }
+        }
+        self.current_bet = to;
+    }
+
+    /// Move up to `amount` chips from the player's stack to the pot, returning how many moved.
+    fn put_in(&mut self, seat: usize, amount: u64) -> u64 {
+        let seat = &mut self.seats[seat];
+        let put_in = amount.min(seat.stack);
+        seat.stack -= put_in;
+        seat.street_bet += put_in;
+        seat.contribution += put_in;
+        if seat.stack == 0 {
+            seat.all_in = true;
+        }
+        put_in
+    }
+
+    fn next_seat(&self, seat: usize) -> usize {
+        (seat + 1) % self.seats.len()
+    }
+
+    fn needs_to_act(&self, seat: usize) -> bool {
+        let seat = &self.seats[seat];
+        !seat.folded && !seat.all_in && (!seat.acted || seat.street_bet < self.current_bet)
+    }
+
+    /// The first player left of `seat` who still has to act on this street.
+    fn find_next_to_act(&self, seat: usize) -> Option<usize> {
+        let mut next = seat;
+        for _ in 0..self.seats.len() {
+            next = self.next_seat(next);
+            if self.needs_to_act(next) {
+                return Some(next);
+            }
+        }
+        None
+    }
+
+    fn players_in_hand(&self) -> usize {
+        self.seats.iter().filter(|s| !s.folded).count()
+    }
+
+    fn players_able_to_bet(&self) -> usize {
+        self.seats.iter().filter(|s| !s.folded && !s.all_in).count()
+    }
+
+    fn advance(&mut self, seat: usize) -> Result<(), GameError> {
+        if self.players_in_hand() == 1 {
+            return self.showdown();
+        }
+        self.to_act = self.find_next_to_act(seat);
+        if self.to_act.is_some() {
+            return Ok(());
+        }
+        self.finish_street()
+    }
+
+    /// Deal the next streets until some betting is possible, or show down after the river.
+    fn finish_street(&mut self) -> Result<(), GameError> {
+        self.to_act = None;
+        loop {
+            let (street, card_count) = match self.street {
+                Street::Preflop => (Street::Flop, 3),
+                Street::Flop => (Street::Turn, 1),
+                Street::Turn => (Street::River, 1),
+                Street::River | Street::Showdown => return self.showdown(),
+            };
+            self.deck.burn()?;
+            let cards = self.deck.deal_many(card_count)?;
+            self.board.extend(cards.iter().copied());
+            self.street = street;
+            self.events.push(GameEvent::BoardDealt { street, cards });
+
+            self.current_bet = 0;
+            self.last_raise_size = self.blinds.big;
+            for seat in self.seats.iter_mut() {
+                seat.street_bet = 0;
+                seat.acted = false;
+            }
+            if self.players_able_to_bet() >= 2 {
+                self.to_act = self.find_next_to_act(self.button);
+                return Ok(());
+            }
+        }
+    }
+
+    fn showdown(&mut self) -> Result<(), GameError> {
+        self.to_act = None;
+        let contested = self.players_in_hand() > 1;
+
+        let mut stakes = Vec::with_capacity(self.seats.len());
+        for n in 1..=self.seats.len() {
+            let seat_index = (self.button + n) % self.seats.len();
+            let seat = &self.seats[seat_index];
+            let best = if contested && !seat.folded {
+                let cards = Vec::from_iter(seat.hole_cards.iter().chain(&self.board).copied());
+                best_hand_from(&cards)
+            } else {
+                None
+            };
+            if let Some(best) = best.as_ref() {
+                self.events.push(GameEvent::ShowedDown {
+                    seat: seat_index,
+                    cards: best.cards.clone(),
+                    category: best.hand.ranking_category(),
+                });
+            }
+            stakes.push(Stake {
+                seat: seat_index,
+                contribution: seat.contribution,
+                all_in: seat.all_in,
+                folded: seat.folded,
+                hand: best.map(|b| b.hand),
+            });
+        }
+        if contested {
+            self.street = Street::Showdown;
+        }
+
+        let settlement = settle(&stakes, self.button)
+            .expect("every player still in the hand has matched the bet or is all-in");
+        for (stake, payout) in stakes.iter().zip(settlement.payouts) {
+            if payout > 0 {
+                self.seats[stake.seat].stack += payout;
+                self.events.push(GameEvent::PotWon { seat: stake.seat, amount: payout });
+            }
+        }
+        self.events.push(GameEvent::HandFinished);
+        Ok(())
+    }
+}
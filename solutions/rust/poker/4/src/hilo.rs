@@ -0,0 +1,88 @@
+//! Hi-Lo split pots, the low half going to the best Ace-to-Five low of eight or better.
+
+use crate::omaha::{best_omaha_hand, omaha_combinations};
+use crate::rules::{AceToFiveLow, High, RankingRules};
+use crate::{Card, Hand, InvalidHand};
+
+/// Who takes each half of a Hi-Lo pot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HiLoShowdown<T> {
+    pub high: Vec<T>,
+    /// `None` when no hand qualifies for low, the high winners then taking the whole pot.
+    pub low: Option<Vec<T>>,
+    /// Winners of both halves, whether alone or sharing them.
+    pub scoops: Vec<T>,
+}
+
+impl<T: Copy + PartialEq> HiLoShowdown<T> {
+    fn new(high: Vec<T>, low: Option<Vec<T>>) -> Self {
+        let scoops = match &low {
+            Some(low) => Vec::from_iter(high.iter().copied().filter(|h| low.contains(h))),
+            None => high.clone(),
+        };
+        HiLoShowdown { high, low, scoops }
+    }
+}
+
+/// Whether a hand is a low of eight or better: five distinct ranks from Ace to Eight.
+pub fn qualifies_eight_or_better(hand: &Hand) -> bool {
+    let groups = hand.grouped_values(1);
+    groups.len() == 5 && groups.iter().all(|&(_count, value)| value <= 8)
+}
+
+/// Split a pot between five-card hands, e.g. at Stud Hi-Lo.
+pub fn hilo_showdown<'a>(hands: &[&'a str]) -> Result<HiLoShowdown<&'a str>, InvalidHand<'a>> {
+    let mut parsed_hands: Vec<(&'a str, Hand)> = Vec::with_capacity(hands.len());
+    for (index, &hand) in hands.iter().enumerate() {
+        let parsed: Hand = hand.parse()
+            .map_err(|error| InvalidHand { index, hand, error })?;
+        parsed_hands.push((hand, parsed));
+    }
+
+    let high = top_scored(parsed_hands.iter()
+        .map(|(hand, parsed)| (*hand, High.score(parsed)))
+    );
+    let low = top_scored(parsed_hands.iter()
+        .filter(|(_h, parsed)| qualifies_eight_or_better(parsed))
+        .map(|(hand, parsed)| (*hand, AceToFiveLow.score(parsed)))
+    );
+
+    Ok(HiLoShowdown::new(high, Some(low).filter(|low| !low.is_empty())))
+}
+
+/// Split an Omaha Hi-Lo pot, each half being played with its own two hole cards and three
+/// board cards. Winners are given by their index in `players`.
+pub fn omaha_hilo_showdown(board: &[Card], players: &[[Card; 4]]) -> HiLoShowdown<usize> {
+    let high = top_scored(players.iter()
+        .enumerate()
+        .filter_map(|(i, hole_cards)| best_omaha_hand(hole_cards, board)
+            .map(|best| (i, High.score(&best.hand)))
+        )
+    );
+    let low = top_scored(players.iter()
+        .enumerate()
+        .filter_map(|(i, hole_cards)| omaha_combinations(hole_cards, board).into_iter()
+            .map(Hand::from_cards)
+            .filter(qualifies_eight_or_better)
+            .map(|hand| AceToFiveLow.score(&hand))
+            .max()
+            .map(|score| (i, score))
+        )
+    );
+
+    HiLoShowdown::new(high, Some(low).filter(|low| !low.is_empty()))
+}
+
+fn top_scored<T, I>(scored: I) -> Vec<T>
+    where I: Iterator<Item=(T, Vec<u8>)>
+{
+    let scored = Vec::from_iter(scored);
+    let Some(top_score) = scored.iter().map(|(_t, s)| s).max().cloned() else {
+        return vec![];
+    };
+
+    scored.into_iter()
+        .filter(|(_t, score)| *score == top_score)
+        .map(|(t, _s)| t)
+        .collect()
+}
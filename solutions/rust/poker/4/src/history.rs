@@ -0,0 +1,497 @@
+//! PokerStars-style text hand histories: parse them into records, and write them
+//! out of a simulated `Game`.
+//!
+//! Cards are written the PokerStars way, e.g. `Td` or `As`.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::game::{Action, Blinds, Game, GameEvent, Street};
+use crate::holdem::best_hand_from;
+use crate::settlement::{settle, Stake};
+use crate::{Card, Hand, PokerParseError, Rank};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeatRecord {
+    pub seat: usize,
+    pub name: String,
+    pub chips: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionKind {
+    PostSmallBlind(u64),
+    PostBigBlind(u64),
+    Fold,
+    Check,
+    Call(u64),
+    Bet(u64),
+    Raise { by: u64, to: u64 },
+    UncalledBetReturned(u64),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActionRecord {
+    pub street: Street,
+    pub player: String,
+    pub kind: ActionKind,
+    pub all_in: bool,
+}
+
+/// One hand, as told by its history.
+///
+/// Amounts written with a currency sign, as in cash games, are kept in cents: `$2.50` gives 250.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct HandHistory {
+    pub hand_id: String,
+    pub blinds: Option<Blinds>,
+    pub table: String,
+    pub button_seat: usize,
+    pub seats: Vec<SeatRecord>,
+    pub dealt: Vec<(String, [Card; 2])>,
+    pub actions: Vec<ActionRecord>,
+    pub board: Vec<Card>,
+    pub shown: Vec<(String, [Card; 2])>,
+    pub collected: Vec<(String, u64)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HistoryError {
+    Malformed { line: usize, reason: String },
+    BadCard { line: usize, error: PokerParseError },
+    UnknownPlayer { line: usize, name: String },
+    Inconsistent(String),
+    WrongWinners { expected: Vec<String>, reported: Vec<String> },
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HistoryError::Malformed { line, reason } =>
+                write!(formatter, "line {}: {}", line, reason),
+            HistoryError::BadCard { line, error } =>
+                write!(formatter, "line {}: {}", line, error),
+            HistoryError::UnknownPlayer { line, name } =>
+                write!(formatter, "line {}: unknown player '{}'", line, name),
+            HistoryError::Inconsistent(reason) =>
+                write!(formatter, "inconsistent hand: {}", reason),
+            HistoryError::WrongWinners { expected, reported } =>
+                write!(formatter, "winners should be {:?} but {:?} collected", expected, reported),
+        }
+    }
+}
+
+impl Error for HistoryError {}
+
+/// Parse every hand of a text holding several histories.
+pub fn parse_histories(text: &str) -> Result<Vec<HandHistory>, HistoryError> {
+    let mut starts = Vec::from_iter(text.match_indices("PokerStars Hand #").map(|(i, _m)| i));
+    starts.push(text.len());
+
+    starts.windows(2)
+        .map(|bounds| {
+            let first_line = text[..bounds[0]].lines().count();
+            parse_history(&text[bounds[0]..bounds[1]])
+                .map_err(|error| error.shifted(first_line))
+        })
+        .collect()
+}
+
+/// Parse a single hand history. Lines which are not understood, such as the summary
+/// or chat, are skipped.
+pub fn parse_history(text: &str) -> Result<HandHistory, HistoryError> {
+    let mut history = HandHistory::default();
+    let mut street = Street::Preflop;
+    let mut in_summary = false;
+
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+        let malformed = |reason: &str| HistoryError::Malformed { line: line_number, reason: reason.to_string() };
+
+        if let Some(after) = line.strip_prefix("PokerStars Hand #") {
+            history.hand_id = after.split(':').next().unwrap_or_default().to_string();
+            history.blinds = parse_blinds(line);
+        } else if let Some(rest) = line.strip_prefix("Table '") {
+            let (table, rest) = rest.split_once('\'').ok_or_else(|| malformed("unterminated table name"))?;
+            history.table = table.to_string();
+            let button = rest.split_once("Seat #").map(|(_b, after)| after.split_whitespace().next().unwrap_or(""));
+            history.button_seat = button.and_then(|b| b.parse().ok())
+                .ok_or_else(|| malformed("missing button seat"))?;
+        } else if line.starts_with("*** ") {
+            in_summary = line.starts_with("*** SUMMARY");
+            let new_street = match line.split(" ***").next().unwrap() {
+                "*** FLOP" => Some(Street::Flop),
+                "*** TURN" => Some(Street::Turn),
+                "*** RIVER" => Some(Street::River),
+                "*** SHOW DOWN" => Some(Street::Showdown),
+                _ => None,
+            };
+            if let Some(new_street) = new_street {
+                street = new_street;
+                if street != Street::Showdown {
+                    // Only the last bracket holds the new cards.
+                    let (_before, new_cards) = line.rsplit_once('[').ok_or_else(|| malformed("missing board cards"))?;
+                    history.board.extend(parse_cards(new_cards.trim_end_matches(']'), line_number)?);
+                }
+            }
+        } else if in_summary {
+            continue;
+        } else if let Some(seat) = parse_seat(line, line_number)? {
+            history.seats.push(seat);
+        } else if let Some(rest) = line.strip_prefix("Dealt to ") {
+            let (name, cards) = rest.rsplit_once(" [").ok_or_else(|| malformed("missing hole cards"))?;
+            let cards = two_cards(parse_cards(cards.trim_end_matches(']'), line_number)?, line_number)?;
+            history.dealt.push((name.to_string(), cards));
+        } else if let Some(rest) = line.strip_prefix("Uncalled bet (") {
+            let (amount, name) = rest.split_once(") returned to ").ok_or_else(|| malformed("bad uncalled bet"))?;
+            history.actions.push(ActionRecord {
+                street,
+                player: history.known_player(name, line_number)?,
+                kind: ActionKind::UncalledBetReturned(parse_amount(amount, line_number)?),
+                all_in: false,
+            });
+        } else if let Some((name, amount)) = line.split_once(" collected ") {
+            let amount = amount.split_whitespace().next().unwrap_or("");
+            let name = history.known_player(name, line_number)?;
+            history.collected.push((name, parse_amount(amount, line_number)?));
+        } else if let Some((name, rest)) = history.split_player(line) {
+            let name = name.to_string();
+            if let Some(cards) = rest.strip_prefix("shows [") {
+                let cards = cards.split(']').next().unwrap();
+                let cards = two_cards(parse_cards(cards, line_number)?, line_number)?;
+                history.shown.push((name, cards));
+            } else if let Some(kind) = parse_action(rest, line_number)? {
+                let all_in = rest.ends_with("and is all-in");
+                history.actions.push(ActionRecord { street, player: name, kind, all_in });
+            }
+        }
+    }
+
+    if history.seats.is_empty() {
+        return Err(HistoryError::Inconsistent("no seated players".to_string()));
+    }
+    Ok(history)
+}
+
+impl HistoryError {
+    fn shifted(self, lines: usize) -> Self {
+        match self {
+            HistoryError::Malformed { line, reason } =>
+                HistoryError::Malformed { line: line + lines, reason },
+            HistoryError::BadCard { line, error } =>
+                HistoryError::BadCard { line: line + lines, error },
+            HistoryError::UnknownPlayer { line, name } =>
+                HistoryError::UnknownPlayer { line: line + lines, name },
+            other => other,
+        }
+    }
+}
+
+impl HandHistory {
+    fn known_player(&self, name: &str, line: usize) -> Result<String, HistoryError> {
+        let name = name.trim();
+        match self.seats.iter().find(|s| s.name == name) {
+            Some(seat) => Ok(seat.name.clone()),
+            None => Err(HistoryError::UnknownPlayer { line, name: name.to_string() }),
+        }
+    }
+
+    /// Split `Name: what they did` for a seated player, names being free to hold ": ".
+    fn split_player<'a>(&self, line: &'a str) -> Option<(&'a str, &'a str)> {
+        self.seats.iter()
+            .filter_map(|seat| {
+                let rest = line.strip_prefix(seat.name.as_str())?.strip_prefix(": ")?;
+                Some((&line[..seat.name.len()], rest))
+            })
+            .max_by_key(|(name, _rest)| name.len())
+    }
+
+    /// Check the players who collected the pot are those `Hand::ranking` makes win.
+    pub fn verify_winners(&self) -> Result<(), HistoryError> {
+        let mut stakes_by_name: HashMap<&str, Stake> = HashMap::from_iter(self.seats.iter()
+            .map(|seat| (seat.name.as_str(), Stake {
+                seat: seat.seat,
+                contribution: 0,
+                all_in: false,
+                folded: false,
+                hand: None,
+            }))
+        );
+        let mut street_bets: HashMap<&str, u64> = HashMap::new();
+        let mut street = Street::Preflop;
+        for action in self.actions.iter() {
+            if action.street != street {
+                street = action.street;
+                street_bets.clear();
+            }
+            let street_bet = street_bets.entry(&action.player).or_default();
+            let stake = stakes_by_name.get_mut(action.player.as_str()).unwrap();
+            match action.kind {
+                ActionKind::PostSmallBlind(amount) | ActionKind::PostBigBlind(amount)
+                | ActionKind::Call(amount) | ActionKind::Bet(amount) => {
+                    stake.contribution += amount;
+                    *street_bet += amount;
+                }
+                ActionKind::Raise { to, .. } => {
+                    stake.contribution += to.saturating_sub(*street_bet);
+                    *street_bet = to;
+                }
+                ActionKind::UncalledBetReturned(amount) => {
+                    stake.contribution = stake.contribution.saturating_sub(amount);
+                }
+                ActionKind::Fold => stake.folded = true,
+                ActionKind::Check => {}
+            }
+            stake.all_in |= action.all_in;
+        }
+
+        // Seats which never act nor show, such as players sitting out, are not in the hand.
+        for (name, stake) in stakes_by_name.iter_mut() {
+            let acted = self.actions.iter().any(|action| action.player == *name);
+            let showed = self.shown.iter().any(|(shown_name, _c)| shown_name == name);
+            if !acted && !showed {
+                stake.folded = true;
+            }
+        }
+
+        let went_to_showdown = !self.shown.is_empty();
+        for (name, stake) in stakes_by_name.iter_mut() {
+            match self.shown.iter().find(|(shown_name, _c)| shown_name == name) {
+                Some((_n, hole_cards)) => {
+                    let cards = Vec::from_iter(hole_cards.iter().chain(&self.board).copied());
+                    let best = best_hand_from(&cards).ok_or_else(|| {
+                        HistoryError::Inconsistent(format!("{} shows down without a full board", name))
+                    })?;
+                    stake.hand = Some(best.hand);
+                }
+                // Mucking at showdown gives up the pot.
+                None if went_to_showdown => stake.folded = true,
+                None => {}
+            }
+        }
+
+        let mut names = Vec::from_iter(stakes_by_name.keys().copied());
+        names.sort_by_key(|name| stakes_by_name[name].seat);
+        let stakes = Vec::from_iter(names.iter().map(|name| stakes_by_name[name].clone()));
+        let settlement = settle(&stakes, self.button_seat)
+            .map_err(|error| HistoryError::Inconsistent(error.to_string()))?;
+
+        let mut expected = Vec::from_iter(names.iter().zip(settlement.payouts)
+            .filter(|(_name, payout)| *payout > 0)
+            .map(|(name, _p)| name.to_string())
+        );
+        let mut reported = Vec::from_iter(self.collected.iter().map(|(name, _a)| name.clone()));
+        expected.sort();
+        reported.sort();
+        reported.dedup();
+        if expected != reported {
+            return Err(HistoryError::WrongWinners { expected, reported });
+        }
+        Ok(())
+    }
+}
+
+fn parse_blinds(line: &str) -> Option<Blinds> {
+    let inside = line.split_once('(')?.1.split_once(')')?.0;
+    let (small, big) = inside.split_once('/')?;
+    let small = parse_money(small)?;
+    let big = parse_money(big.split_whitespace().next()?)?;
+    Some(Blinds { small, big })
+}
+
+/// `Seat 3: Name (1500 in chips)` or `Seat 3: Name ($2.00 in chips)`, lines not starting
+/// with `Seat 3: ` giving `None`.
+fn parse_seat(line: &str, line_number: usize) -> Result<Option<SeatRecord>, HistoryError> {
+    let Some((seat, rest)) = line.strip_prefix("Seat ").and_then(|rest| rest.split_once(": ")) else {
+        return Ok(None);
+    };
+    let Ok(seat) = seat.parse() else {
+        return Ok(None);
+    };
+    let malformed = || HistoryError::Malformed { line: line_number, reason: format!("bad seat line '{}'", line) };
+    // Whatever follows the chips, such as "is sitting out", is ignored.
+    let (before_chips, _rest) = rest.split_once(" in chips").ok_or_else(malformed)?;
+    let (name, chips) = before_chips.rsplit_once(" (").ok_or_else(malformed)?;
+
+    Ok(Some(SeatRecord { seat, name: name.to_string(), chips: parse_amount(chips, line_number)? }))
+}
+
+fn parse_action(text: &str, line: usize) -> Result<Option<ActionKind>, HistoryError> {
+    let words = Vec::from_iter(text.split_whitespace());
+    let amount = |i: usize| match words.get(i) {
+        Some(word) => parse_amount(word, line),
+        None => Err(HistoryError::Malformed { line, reason: format!("missing amount in '{}'", text) }),
+    };
+
+    let kind = match words.as_slice() {
+        ["posts", "small", "blind", ..] => ActionKind::PostSmallBlind(amount(3)?),
+        ["posts", "big", "blind", ..] => ActionKind::PostBigBlind(amount(3)?),
+        ["folds", ..] => ActionKind::Fold,
+        ["checks", ..] => ActionKind::Check,
+        ["calls", ..] => ActionKind::Call(amount(1)?),
+        ["bets", ..] => ActionKind::Bet(amount(1)?),
+        ["raises", _, "to", ..] => ActionKind::Raise { by: amount(1)?, to: amount(3)? },
+        _ => return Ok(None),
+    };
+    Ok(Some(kind))
+}
+
+fn parse_amount(text: &str, line: usize) -> Result<u64, HistoryError> {
+    parse_money(text)
+        .ok_or_else(|| HistoryError::Malformed { line, reason: format!("bad amount '{}'", text) })
+}
+
+/// Chips as a whole number, or money in cents when there is a currency sign, e.g. `$0.25`.
+fn parse_money(text: &str) -> Option<u64> {
+    let Some(money) = text.strip_prefix(['$', '€', '£']) else {
+        return text.parse().ok();
+    };
+    let (units, cents) = money.split_once('.').unwrap_or((money, ""));
+    let all_digits = |digits: &str| digits.bytes().all(|b| b.is_ascii_digit());
+    if units.is_empty() || !all_digits(units) || cents.len() > 2 || !all_digits(cents) {
+        return None;
+    }
+    let cents = format!("{:0<2}", cents).parse::<u64>().ok()?;
+    units.parse::<u64>().ok()?.checked_mul(100)?.checked_add(cents)
+}
+
+fn parse_cards(text: &str, line: usize) -> Result<Vec<Card>, HistoryError> {
+    text.split_whitespace()
+        .map(|token| {
+            // PokerStars writes suits in lowercase.
+            token.to_uppercase().parse::<Card>()
+                .map_err(|error| HistoryError::BadCard { line, error })
+        })
+        .collect()
+}
+
+fn two_cards(cards: Vec<Card>, line: usize) -> Result<[Card; 2], HistoryError> {
+    cards.try_into()
+        .map_err(|_c| HistoryError::Malformed { line, reason: "expected two hole cards".to_string() })
+}
+
+/// A card written the PokerStars way, e.g. `Td`.
+pub fn stars_notation(card: &Card) -> String {
+    let rank = match card.rank {
+        Rank::Ten => "T".to_string(),
+        rank => rank.to_string(),
+    };
+    format!("{}{}", rank, card.suit.letter().to_ascii_lowercase())
+}
+
+fn stars_cards(cards: &[Card]) -> String {
+    format!("[{}]", Vec::from_iter(cards.iter().map(stars_notation)).join(" "))
+}
+
+/// Write the history of a played `Game`, seat `i` being played by `names[i]`.
+pub fn write_history(game: &Game, names: &[&str], hand_id: u64, table: &str) -> String {
+    let blinds = game.blinds();
+    let mut text = String::new();
+    let mut line = |content: String| {
+        text.push_str(&content);
+        text.push('\n');
+    };
+
+    line(format!("PokerStars Hand #{}: Hold'em No Limit ({}/{})", hand_id, blinds.small, blinds.big));
+    line(format!("Table '{}' {}-max Seat #{} is the button", table, game.player_count(), game.button() + 1));
+    for (seat, stack) in game.starting_stacks().iter().enumerate() {
+        line(format!("Seat {}: {} ({} in chips)", seat + 1, names[seat], stack));
+    }
+
+    let (uncalled_seat, uncalled) = uncalled_bet(game);
+    let mut street_bets = vec![0; game.player_count()];
+    let mut current_bet = 0;
+    let mut board: Vec<Card> = vec![];
+    let mut blinds_posted = 0;
+    let mut hole_cards_header = false;
+    let mut results_started = false;
+
+    for event in game.events() {
+        match event {
+            GameEvent::BlindPosted { seat, amount } => {
+                let which = if blinds_posted == 0 { "small" } else { "big" };
+                blinds_posted += 1;
+                line(format!("{}: posts {} blind {}", names[*seat], which, amount));
+                street_bets[*seat] += amount;
+                current_bet = current_bet.max(street_bets[*seat]);
+            }
+            GameEvent::HoleCardsDealt { seat, cards } => {
+                if !hole_cards_header {
+                    line("*** HOLE CARDS ***".to_string());
+                    hole_cards_header = true;
+                }
+                line(format!("Dealt to {} {}", names[*seat], stars_cards(cards)));
+            }
+            GameEvent::Acted { seat, action, put_in, all_in } => {
+                let to = street_bets[*seat] + put_in;
+                let what = match action {
+                    Action::Fold => "folds".to_string(),
+                    Action::Check => "checks".to_string(),
+                    _ if to > current_bet && current_bet == 0 => format!("bets {}", put_in),
+                    _ if to > current_bet => format!("raises {} to {}", to - current_bet, to),
+                    _ => format!("calls {}", put_in),
+                };
+                let all_in = if *all_in { " and is all-in" } else { "" };
+                line(format!("{}: {}{}", names[*seat], what, all_in));
+                street_bets[*seat] = to;
+                current_bet = current_bet.max(to);
+            }
+            GameEvent::BoardDealt { street, cards } => {
+                let name = match street {
+                    Street::Flop => "FLOP",
+                    Street::Turn => "TURN",
+                    _ => "RIVER",
+                };
+                if board.is_empty() {
+                    line(format!("*** {} *** {}", name, stars_cards(cards)));
+                } else {
+                    line(format!("*** {} *** {} {}", name, stars_cards(&board), stars_cards(cards)));
+                }
+                board.extend(cards.iter().copied());
+                street_bets.iter_mut().for_each(|b| *b = 0);
+                current_bet = 0;
+            }
+            GameEvent::ShowedDown { seat, cards, .. } => {
+                if !results_started {
+                    results_started = true;
+                    if uncalled > 0 {
+                        line(format!("Uncalled bet ({}) returned to {}", uncalled, names[uncalled_seat]));
+                    }
+                    line("*** SHOW DOWN ***".to_string());
+                }
+                let description = Hand::try_from(cards.as_slice()).unwrap().describe();
+                line(format!("{}: shows {} ({})", names[*seat], stars_cards(&game.hole_cards(*seat)), description));
+            }
+            GameEvent::PotWon { seat, amount } => {
+                if !results_started {
+                    results_started = true;
+                    if uncalled > 0 {
+                        line(format!("Uncalled bet ({}) returned to {}", uncalled, names[uncalled_seat]));
+                    }
+                }
+                let amount = if *seat == uncalled_seat { amount - uncalled } else { *amount };
+                if amount > 0 {
+                    line(format!("{} collected {} from pot", names[*seat], amount));
+                }
+            }
+            GameEvent::HandFinished => {
+                line("*** SUMMARY ***".to_string());
+                line(format!("Total pot {} | Rake 0", game.pot() - uncalled));
+                if !board.is_empty() {
+                    line(format!("Board {}", stars_cards(&board)));
+                }
+            }
+        }
+    }
+    text
+}
+
+/// The chips put in by the top contributor that nobody matched.
+fn uncalled_bet(game: &Game) -> (usize, u64) {
+    let mut contributions = Vec::from_iter((0..game.player_count()).map(|seat| (game.contribution(seat), seat)));
+    contributions.sort_unstable_by(|a, b| b.cmp(a));
+    let (top, seat) = contributions[0];
+    (seat, top - contributions[1].0)
+}
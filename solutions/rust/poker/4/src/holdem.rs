@@ -0,0 +1,55 @@
+use crate::{combinations, Card, Hand, HandRank};
+
+/// The best five-card hand found among more cards, with the cards it was made of.
+pub struct BestHand {
+    pub hand: Hand,
+    pub ranking: HandRank,
+    pub cards: Vec<Card>,
+}
+
+/// Pick the best five-card `Hand` out of `cards`, e.g. two hole cards plus the board.
+///
+/// Returns `None` when fewer than five cards are given.
+pub fn best_hand_from(cards: &[Card]) -> Option<BestHand> {
+    combinations(cards, 5).into_iter()
+        .map(BestHand::from_five_cards)
+        .max_by(|a, b| a.ranking.cmp(&b.ranking))
+}
+
+impl BestHand {
+    pub(crate) fn from_five_cards(five_cards: Vec<Card>) -> Self {
+        let hand = Hand::from_cards(five_cards.clone());
+        let ranking = hand.ranking();
+        BestHand { hand, ranking, cards: five_cards }
+    }
+}
+
+/// Given the board and each player's hole cards, return the index and best hand
+/// of the player(s) who win the showdown.
+///
+/// # Panics
+///
+/// Panics if the board has fewer than three cards, no player having a hand yet.
+pub fn holdem_winners(board: &[Card], players: &[[Card; 2]]) -> Vec<(usize, BestHand)> {
+    assert!(board.len() >= 3, "a showdown needs at least the flop on the board");
+
+    let best_hands = Vec::from_iter(players.iter()
+        .enumerate()
+        .map(|(i, hole_cards)| {
+            let cards = Vec::from_iter(hole_cards.iter().chain(board).copied());
+            (i, best_hand_from(&cards).unwrap())
+        })
+    );
+    keep_top_ranked(best_hands)
+}
+
+/// Keep only the players whose best hand has the top ranking.
+pub(crate) fn keep_top_ranked(best_hands: Vec<(usize, BestHand)>) -> Vec<(usize, BestHand)> {
+    let Some(top_ranking) = best_hands.iter().map(|(_i, b)| &b.ranking).max().cloned() else {
+        return vec![];
+    };
+
+    best_hands.into_iter()
+        .filter(|(_i, best)| best.ranking == top_ranking)
+        .collect()
+}
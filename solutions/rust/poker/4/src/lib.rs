@@ -1,66 +1,279 @@
 use std::collections::HashMap;
+use std::error::Error;
 use std::fmt;
+use std::str::FromStr;
+use evaluator::CardSet;
+use variant::Variant;
 use RankingCategory::*;
 
-/// Given a list of poker hands, return a list of those hands which win.
+pub mod arena;
+pub mod compare;
+pub mod deck;
+pub mod describe;
+pub mod discard;
+pub mod equity;
+pub mod evaluator;
+pub mod game;
+pub mod hilo;
+pub mod history;
+pub mod holdem;
+pub mod omaha;
+pub mod outs;
+pub mod range;
+pub mod rules;
+#[cfg(feature = "serde")]
+mod serde;
+pub mod settlement;
+pub mod stud;
+pub mod tournament;
+pub mod variant;
+pub mod wild;
+
+/// Reasons why a card or a hand could not be parsed.
 ///
-/// Note the type signature: this function should return _the same_ reference to
-/// the winning hand(s) as were passed in, not reconstructed strings which happen to be equal.
-#[derive(PartialOrd, PartialEq, Clone, Debug)]
-pub struct Card {
-    pub rank: u8,
-    suit: char,
-    display_string: String,
+/// Every variant pointing at a token carries the byte `offset` of that token
+/// within the parsed input, except `RepeatedCard` which comes from a slice of cards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PokerParseError {
+    EmptyInput,
+    BadRank { token: String, offset: usize },
+    BadSuit { token: String, offset: usize },
+    WrongCardCount { count: usize },
+    DuplicateCard { token: String, offset: usize },
+    /// A card given twice to `Hand::try_from(&[Card])`, at `index` in the slice.
+    RepeatedCard { card: Card, index: usize },
 }
 
-impl Card {
-    pub fn from_string(input: &str) -> Self {
-        let suit = input.chars().last().unwrap();
-        let rank = Self::rank_from_str(input.strip_suffix(suit).unwrap());
+impl PokerParseError {
+    fn shifted(self, by: usize) -> Self {
+        match self {
+            PokerParseError::BadRank { token, offset } =>
+                PokerParseError::BadRank { token, offset: offset + by },
+            PokerParseError::BadSuit { token, offset } =>
+                PokerParseError::BadSuit { token, offset: offset + by },
+            PokerParseError::DuplicateCard { token, offset } =>
+                PokerParseError::DuplicateCard { token, offset: offset + by },
+            other => other,
+        }
+    }
+}
 
-        Card { rank, suit, display_string: input.to_string() }
+impl fmt::Display for PokerParseError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PokerParseError::EmptyInput =>
+                write!(formatter, "empty input"),
+            PokerParseError::BadRank { token, offset } =>
+                write!(formatter, "invalid rank '{}' at byte {}", token, offset),
+            PokerParseError::BadSuit { token, offset } =>
+                write!(formatter, "invalid suit '{}' at byte {}", token, offset),
+            PokerParseError::WrongCardCount { count } =>
+                write!(formatter, "expected 5 cards, got {}", count),
+            PokerParseError::DuplicateCard { token, offset } =>
+                write!(formatter, "duplicate card '{}' at byte {}", token, offset),
+            PokerParseError::RepeatedCard { card, index } =>
+                write!(formatter, "duplicate card '{}' at index {}", card, index),
+        }
     }
+}
+
+impl Error for PokerParseError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rank {
+    Two = 2,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
 
-    pub fn new(rank: u8, suit: char) -> Self {
-        let mut string = Self::string_from_rank(rank).unwrap();
-        string.push(suit);
+impl Rank {
+    pub const ALL: [Rank; 13] = [
+        Rank::Two, Rank::Three, Rank::Four, Rank::Five, Rank::Six, Rank::Seven, Rank::Eight,
+        Rank::Nine, Rank::Ten, Rank::Jack, Rank::Queen, Rank::King, Rank::Ace,
+    ];
+
+    /// All the ranks, from Two up to Ace.
+    pub fn all() -> impl DoubleEndedIterator<Item=Rank> {
+        Self::ALL.into_iter()
+    }
 
-        Card { rank, suit, display_string: string }
+    /// The rank number, from 2 up to 14 for an Ace.
+    pub fn value(self) -> u8 {
+        self as u8
     }
 
-    pub fn string_from_rank(rank: u8) -> Result<String, String> {
-        match rank {
-            2..=10 => Ok(format!("{}", rank)),
-            11 => Ok("J".to_string()),
-            12 => Ok("Q".to_string()),
-            13 => Ok("K".to_string()),
-            1 | 14 => Ok("A".to_string()),
-            _ => Err(format!("Invalid rank number: '{}'", rank))
+    /// The rank for a number, 1 and 14 both meaning Ace.
+    pub fn from_value(value: u8) -> Option<Rank> {
+        match value {
+            1 => Some(Rank::Ace),
+            2..=14 => Some(Self::ALL[value as usize - 2]),
+            _ => None,
         }
     }
 
-    pub fn rank_from_str(input: &str) -> u8 {
-        let faces = ["J", "Q", "K", "A"];
-        let mut rank: u8;
+    pub fn symbol(self) -> &'static str {
+        ["2", "3", "4", "5", "6", "7", "8", "9", "10", "J", "Q", "K", "A"][self as usize - 2]
+    }
+}
+
+impl fmt::Display for Rank {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.symbol())
+    }
+}
+
+impl TryFrom<char> for Rank {
+    type Error = PokerParseError;
+
+    /// Single character ranks, `T` standing for Ten.
+    fn try_from(input: char) -> Result<Self, Self::Error> {
+        match input {
+            '2'..='9' => Ok(Self::ALL[input as usize - '2' as usize]),
+            'T' => Ok(Rank::Ten),
+            'J' => Ok(Rank::Jack),
+            'Q' => Ok(Rank::Queen),
+            'K' => Ok(Rank::King),
+            'A' => Ok(Rank::Ace),
+            _ => Err(PokerParseError::BadRank { token: input.to_string(), offset: 0 }),
+        }
+    }
+}
+
+impl FromStr for Rank {
+    type Err = PokerParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut chars = input.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Rank::try_from(c),
+            _ if input == "10" => Ok(Rank::Ten),
+            _ => Err(PokerParseError::BadRank { token: input.to_string(), offset: 0 }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Suit {
+    Clubs,
+    Diamonds,
+    Hearts,
+    Spades,
+}
+
+impl Suit {
+    pub const ALL: [Suit; 4] = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
+
+    pub fn all() -> impl DoubleEndedIterator<Item=Suit> {
+        Self::ALL.into_iter()
+    }
+
+    pub fn letter(self) -> char {
+        ['C', 'D', 'H', 'S'][self as usize]
+    }
+
+    pub fn symbol(self) -> char {
+        ['♣', '♦', '♥', '♠'][self as usize]
+    }
+}
 
-        if faces.contains(&input) {
-            rank = faces.iter().enumerate().find(|(_i, &f)| f == input).unwrap().0 as u8;
-            rank += 1 + 10;
+impl fmt::Display for Suit {
+    /// The suit letter, or its Unicode symbol with the alternate flag (`{:#}`).
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        if formatter.alternate() {
+            write!(formatter, "{}", self.symbol())
         } else {
-            rank = input.parse().unwrap();
+            write!(formatter, "{}", self.letter())
         }
-        rank
+    }
+}
+
+impl TryFrom<char> for Suit {
+    type Error = PokerParseError;
+
+    fn try_from(input: char) -> Result<Self, Self::Error> {
+        Suit::all()
+            .find(|suit| suit.letter() == input || suit.symbol() == input)
+            .ok_or_else(|| PokerParseError::BadSuit { token: input.to_string(), offset: 0 })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Card {
+    pub rank: Rank,
+    pub suit: Suit,
+}
+
+impl Card {
+    /// # Panics
+    ///
+    /// Panics if `input` is not a valid card, see `str::parse::<Card>` for the fallible version.
+    pub fn from_string(input: &str) -> Self {
+        input.parse().unwrap()
+    }
+
+    pub fn new(rank: Rank, suit: Suit) -> Self {
+        Card { rank, suit }
+    }
+
+    /// The card as it is written in a hand, e.g. `10H`.
+    pub fn notation(&self) -> String {
+        format!("{}{}", self.rank, self.suit)
+    }
+}
+
+/// Split a card such as `10H` or `Q♠` into its rank and suit.
+pub(crate) fn parse_rank_and_suit(input: &str) -> Result<(Rank, Suit), PokerParseError> {
+    let suit_char = input.chars().last().ok_or(PokerParseError::EmptyInput)?;
+    let rank_str = &input[..input.len() - suit_char.len_utf8()];
+    if rank_str.is_empty() {
+        return Err(PokerParseError::BadRank { token: input.to_string(), offset: 0 });
+    }
+    let rank: Rank = rank_str.parse()?;
+    let suit = Suit::try_from(suit_char).map_err(|e| e.shifted(rank_str.len()))?;
+
+    Ok((rank, suit))
+}
+
+impl FromStr for Card {
+    type Err = PokerParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (rank, suit) = parse_rank_and_suit(input)?;
+        Ok(Card { rank, suit })
+    }
+}
+
+impl TryFrom<&str> for Card {
+    type Error = PokerParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        input.parse()
     }
 }
 
 impl fmt::Display for Card {
+    /// Written as `[10H]`, or `[10♥]` with the alternate flag (`{:#}`).
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(formatter, "[{}]", self.display_string)
+        if formatter.alternate() {
+            write!(formatter, "[{}{:#}]", self.rank, self.suit)
+        } else {
+            write!(formatter, "[{}{}]", self.rank, self.suit)
+        }
     }
 }
 
 #[repr(u8)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum RankingCategory {
     HighestCard = 0,
     OnePair,
@@ -71,74 +284,239 @@ pub enum RankingCategory {
     Full,
     Quads,
     StraightFlush,
+    /// Only reachable with wild cards, see the `wild` module.
+    FiveOfAKind,
 }
 
+impl RankingCategory {
+    pub fn name(self) -> &'static str {
+        match self {
+            HighestCard => "High card",
+            OnePair => "One pair",
+            TwoPair => "Two pair",
+            Trips => "Three of a kind",
+            Straight => "Straight",
+            Flush => "Flush",
+            Full => "Full house",
+            Quads => "Four of a kind",
+            StraightFlush => "Straight flush",
+            FiveOfAKind => "Five of a kind",
+        }
+    }
+
+    /// A stable snake_case identifier, e.g. `full_house`, for machine-readable output.
+    pub fn id(self) -> &'static str {
+        match self {
+            HighestCard => "high_card",
+            OnePair => "one_pair",
+            TwoPair => "two_pair",
+            Trips => "three_of_a_kind",
+            Straight => "straight",
+            Flush => "flush",
+            Full => "full_house",
+            Quads => "four_of_a_kind",
+            StraightFlush => "straight_flush",
+            FiveOfAKind => "five_of_a_kind",
+        }
+    }
+
+    pub const ALL: [RankingCategory; 10] = [
+        HighestCard, OnePair, TwoPair, Trips, Straight, Flush, Full, Quads, StraightFlush, FiveOfAKind,
+    ];
+}
+
+impl fmt::Display for RankingCategory {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.name())
+    }
+}
+
+/// How strong a hand is: hands compare by category first, then kicker by kicker.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandRank {
+    strength: u8,
+    category: RankingCategory,
+    values: Vec<u8>,
+}
+
+impl HandRank {
+    pub fn category(&self) -> RankingCategory {
+        self.category
+    }
+
+    /// The distinct ranks breaking ties within the category, most significant first,
+    /// e.g. the trips then the kickers. The Ace of a wheel comes last.
+    pub fn kickers(&self) -> Vec<Rank> {
+        Vec::from_iter(self.values.iter().map(|&value| Rank::from_value(value).unwrap()))
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Hand {
     sorted_cards: Vec<Card>,
+    ace_low: bool,
+    variant: Variant,
 }
 
 impl Hand {
+    /// # Panics
+    ///
+    /// Panics if `input` is not a valid hand, see `str::parse::<Hand>` for the fallible version.
     pub fn from_string(input: &str) -> Self {
-        let input_cards: Vec<Card> = input.split_whitespace().map(Card::from_string).collect();
+        input.parse().unwrap()
+    }
 
+    /// Parse a hand of the given variant, rejecting ranks its deck doesn't have and
+    /// cards seen more often than it has decks.
+    pub fn parse_in(input: &str, variant: &Variant) -> Result<Self, PokerParseError> {
+        if input.trim().is_empty() {
+            return Err(PokerParseError::EmptyInput);
+        }
+
+        let mut input_cards: Vec<Card> = Vec::with_capacity(5);
+        for (offset, token) in tokens_with_offsets(input) {
+            let card: Card = token.parse().map_err(|e: PokerParseError| e.shifted(offset))?;
+            if card.rank < variant.lowest_rank {
+                let (suit_offset, _suit) = token.char_indices().last().unwrap();
+                return Err(PokerParseError::BadRank { token: token[..suit_offset].to_string(), offset });
+            }
+            let copies = input_cards.iter().filter(|&&c| c == card).count();
+            if copies >= variant.decks as usize {
+                return Err(PokerParseError::DuplicateCard { token: token.to_string(), offset });
+            }
+            input_cards.push(card);
+        }
+        if input_cards.len() != 5 {
+            return Err(PokerParseError::WrongCardCount { count: input_cards.len() });
+        }
+
+        Ok(Hand::from_cards_in(input_cards, *variant))
+    }
+
+    fn from_cards(input_cards: Vec<Card>) -> Self {
+        Self::from_cards_in(input_cards, Variant::standard())
+    }
+
+    pub(crate) fn from_cards_in(input_cards: Vec<Card>, variant: Variant) -> Self {
         let mut cards_by_rank = Vec::from_iter(
             Hand::group_cards_by_rank(input_cards.iter())
         );
-        cards_by_rank.sort_by_key(|(rank, cards)|
-            (cards.len() as u32 * 100_u32) + rank.to_owned() as u32
-        );
+        cards_by_rank.sort_by_key(|(rank, cards)| (cards.len(), *rank));
         let mut sorted_cards: Vec<Card> = cards_by_rank.into_iter()
             .flat_map(|(_r, cards)| cards)
-            .cloned().collect();
+            .copied().collect();
 
-        if Self::ace_can_start_a_straight_low(&sorted_cards) {
-            let high_ace = sorted_cards.last().unwrap();
-            let low_ace = Card::new(1, high_ace.suit);
-            sorted_cards = [low_ace].iter().chain(sorted_cards[..4].iter()).cloned().collect();
+        let ace_low = Self::ace_can_start_a_straight_from(&sorted_cards, variant.lowest_rank);
+        if ace_low {
+            sorted_cards.rotate_right(1);
         }
 
-        Hand { sorted_cards }
+        Hand { sorted_cards, ace_low, variant }
     }
 
     pub fn ace_can_start_a_straight_low(sorted_cards: &[Card]) -> bool {
-        let sorted_ranks: Vec<u8> = sorted_cards.iter()
-            .map(|c| c.rank)
-            .collect();
+        Self::ace_can_start_a_straight_from(sorted_cards, Rank::Two)
+    }
 
-        sorted_ranks == vec![2_u8, 3, 4, 5, 14]
+    /// Whether the sorted cards are the four lowest ranks of a deck starting at
+    /// `lowest_rank` plus an Ace, e.g. A-6-7-8-9 in short-deck.
+    pub fn ace_can_start_a_straight_from(sorted_cards: &[Card], lowest_rank: Rank) -> bool {
+        let sorted_ranks = Vec::from_iter(sorted_cards.iter().map(|c| c.rank.value()));
+        let lowest = lowest_rank.value();
+
+        sorted_ranks == vec![lowest, lowest + 1, lowest + 2, lowest + 3, Rank::Ace.value()]
     }
 
-    pub fn ranking(&self) -> u32 {
-        let cards_score: u32 = self.sorted_cards.iter()
-            .enumerate()
-            .map(|(i, card)| card.rank as u32 * 10_u32.pow(i as u32))
-            .sum();
+    /// The rank numbers of the sorted cards, an Ace starting a straight counting as 1.
+    fn sorted_values(&self) -> Vec<u8> {
+        let mut values = Vec::from_iter(self.sorted_cards.iter().map(|c| c.rank.value()));
+        if self.ace_low {
+            values[0] = 1;
+        }
+        values
+    }
 
-        let category_score = self.ranking_category() as u32 * 10_u32.pow(6_u32);
-        cards_score + category_score
+    pub fn ranking(&self) -> HandRank {
+        let category = self.ranking_category();
+        let values = self.tie_break_values(category);
+        HandRank { strength: self.variant.strength(category), category, values }
     }
 
-    fn ranking_category(&self) -> RankingCategory {
-        let a_straight = self.a_straight();
-        let a_flush = self.a_flush();
+    /// The rank numbers in the order they break ties within `category`, most significant first:
+    /// every card from the highest down for flushes and straights, each group once otherwise.
+    fn tie_break_values(&self, category: RankingCategory) -> Vec<u8> {
+        let mut values = self.sorted_values();
+        if matches!(category, Straight | Flush | StraightFlush) {
+            // With several decks, a flush may hold a pair which must not count first.
+            values.sort_unstable_by(|a, b| b.cmp(a));
+            return values;
+        }
+        values.reverse();
+        values.dedup();
+        values
+    }
 
-        if a_straight && a_flush { return StraightFlush; }
-        if a_flush { return Flush; }
-        if a_straight { return Straight; }
-        if self.a_quads() { return Quads; }
-        if self.a_full() { return Full; }
-        if self.a_trips() { return Trips; }
-        if self.a_two_pair() { return TwoPair; }
-        if self.a_one_pair() { return OnePair; }
+    /// `(count, rank number)` of each distinct rank, largest group then highest rank first,
+    /// Aces counting as `ace_value`.
+    fn grouped_values(&self, ace_value: u8) -> Vec<(u8, u8)> {
+        let mut groups = Vec::from_iter(self.counts_by_rank().into_iter()
+            .map(|(rank, count)| {
+                let value = if rank == Rank::Ace { ace_value } else { rank.value() };
+                (count, value)
+            })
+        );
+        groups.sort_unstable_by(|a, b| b.cmp(a));
+        groups
+    }
 
-        HighestCard
+    pub fn ranking_category(&self) -> RankingCategory {
+        if self.ace_low {
+            return if self.a_flush() { StraightFlush } else { Straight };
+        }
+        if self.variant.decks > 1 {
+            return self.counted_category();
+        }
+        evaluator::category_of(evaluator::evaluate(self.card_set()))
     }
 
-    pub fn a_straight(&self) -> bool {
-        let highest_rank = self.sorted_cards[4].rank as i8;
-        let lowest_rank = self.sorted_cards[0].rank as i8;
+    /// The category worked out from the rank counts, which unlike the evaluator copes
+    /// with the same card showing up twice. A hand both paired and flush is the stronger of the two.
+    fn counted_category(&self) -> RankingCategory {
+        let groups = self.grouped_values(14);
+        let pairing = match (groups[0].0, groups.get(1).map(|g| g.0)) {
+            (5, _) => FiveOfAKind,
+            (4, _) => Quads,
+            (3, Some(2)) => Full,
+            (3, _) => Trips,
+            (2, Some(2)) => TwoPair,
+            (2, _) => OnePair,
+            _ => HighestCard,
+        };
+        let suited_or_connected = match (self.a_flush(), self.a_straight()) {
+            (true, true) => Some(StraightFlush),
+            (true, false) => Some(Flush),
+            (false, true) => Some(Straight),
+            (false, false) => None,
+        };
+
+        suited_or_connected.into_iter()
+            .chain([pairing])
+            .max_by_key(|&category| self.variant.strength(category))
+            .unwrap()
+    }
+
+    /// The cards, sorted the way they break ties: smaller groups and lower ranks first.
+    pub fn cards(&self) -> &[Card] {
+        &self.sorted_cards
+    }
+
+    pub fn card_set(&self) -> CardSet {
+        self.sorted_cards.iter().collect()
+    }
 
-        (highest_rank - lowest_rank) == 4
+    pub fn a_straight(&self) -> bool {
+        self.sorted_values().windows(2)
+            .all(|pair| pair[1] == pair[0] + 1)
     }
 
     pub fn a_flush(&self) -> bool {
@@ -146,6 +524,7 @@ impl Hand {
         self.sorted_cards[1..].iter().all(|c| c.suit == suit)
     }
 
+    #[deprecated(note = "use `ranking_category`, which goes through the evaluator")]
     pub fn a_full(&self) -> bool {
         let mut count_by_rank = Vec::from_iter(
             self.counts_by_rank().into_values()
@@ -155,6 +534,7 @@ impl Hand {
         count_by_rank == vec![2_u8, 3]
     }
 
+    #[deprecated(note = "use `ranking_category`, which goes through the evaluator")]
     pub fn a_quads(&self) -> bool {
         let mut count_by_rank = Vec::from_iter(
             self.counts_by_rank().into_values()
@@ -164,6 +544,7 @@ impl Hand {
         count_by_rank == vec![1_u8, 4]
     }
 
+    #[deprecated(note = "use `ranking_category`, which goes through the evaluator")]
     pub fn a_trips(&self) -> bool {
         let mut count_by_rank = Vec::from_iter(
             self.counts_by_rank().into_values()
@@ -173,6 +554,7 @@ impl Hand {
         count_by_rank == vec![1_u8, 1, 3]
     }
 
+    #[deprecated(note = "use `ranking_category`, which goes through the evaluator")]
     pub fn a_two_pair(&self) -> bool {
         let mut count_by_rank = Vec::from_iter(
             self.counts_by_rank().into_values()
@@ -182,6 +564,7 @@ impl Hand {
         count_by_rank == vec![1_u8, 2, 2]
     }
 
+    #[deprecated(note = "use `ranking_category`, which goes through the evaluator")]
     pub fn a_one_pair(&self) -> bool {
         let mut count_by_rank = Vec::from_iter(
             self.counts_by_rank().into_values()
@@ -191,10 +574,10 @@ impl Hand {
         count_by_rank == vec![1_u8, 1, 1, 2]
     }
 
-    pub fn group_cards_by_rank<'a, I>(cards: I) -> HashMap<u8, Vec<&'a Card>>
+    pub fn group_cards_by_rank<'a, I>(cards: I) -> HashMap<Rank, Vec<&'a Card>>
         where I: IntoIterator<Item=&'a Card>
     {
-        let mut cards_by_rank: HashMap<u8, Vec<&Card>> = HashMap::new();
+        let mut cards_by_rank: HashMap<Rank, Vec<&Card>> = HashMap::new();
         for card in cards {
             cards_by_rank
                 .entry(card.rank)
@@ -204,8 +587,8 @@ impl Hand {
         cards_by_rank
     }
 
-    pub fn counts_by_rank(&self) -> HashMap<u8, u8> {
-        let mut count_by_rank: HashMap<u8, u8> = HashMap::new();
+    pub fn counts_by_rank(&self) -> HashMap<Rank, u8> {
+        let mut count_by_rank: HashMap<Rank, u8> = HashMap::new();
 
         for card in self.sorted_cards.iter() {
             count_by_rank.entry(card.rank)
@@ -216,6 +599,85 @@ impl Hand {
     }
 }
 
+impl FromStr for Hand {
+    type Err = PokerParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Hand::parse_in(input, &Variant::standard())
+    }
+}
+
+impl TryFrom<&str> for Hand {
+    type Error = PokerParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        input.parse()
+    }
+}
+
+/// All the ways to pick `k` cards out of `cards`, preserving their order.
+pub(crate) fn combinations(cards: &[Card], k: usize) -> Vec<Vec<Card>> {
+    if k == 0 {
+        return vec![vec![]];
+    }
+    if cards.len() < k {
+        return vec![];
+    }
+    let (first, rest) = cards.split_first().unwrap();
+    let mut with_first: Vec<Vec<Card>> = combinations(rest, k - 1).into_iter()
+        .map(|mut combo| {
+            combo.insert(0, *first);
+            combo
+        })
+        .collect();
+    with_first.extend(combinations(rest, k));
+    with_first
+}
+
+/// Call `visit` with each way to pick `k` cards out of `cards`, in the order `combinations`
+/// lists them, without building them all at once.
+pub(crate) fn for_each_combination<F>(cards: &[Card], k: usize, visit: &mut F)
+    where F: FnMut(&[Card])
+{
+    fn pick<F>(picked: &mut Vec<Card>, cards: &[Card], k: usize, visit: &mut F)
+        where F: FnMut(&[Card])
+    {
+        if k == 0 {
+            visit(picked);
+            return;
+        }
+        for (i, &card) in cards.iter().enumerate().take((cards.len() + 1).saturating_sub(k)) {
+            picked.push(card);
+            pick(picked, &cards[i + 1..], k - 1, visit);
+            picked.pop();
+        }
+    }
+    pick(&mut Vec::with_capacity(k), cards, k, visit);
+}
+
+impl TryFrom<&[Card]> for Hand {
+    type Error = PokerParseError;
+
+    /// Build a hand out of five distinct cards, a duplicate being reported at its index.
+    fn try_from(cards: &[Card]) -> Result<Self, Self::Error> {
+        if cards.len() != 5 {
+            return Err(PokerParseError::WrongCardCount { count: cards.len() });
+        }
+        for (i, card) in cards.iter().enumerate() {
+            if cards[..i].contains(card) {
+                return Err(PokerParseError::RepeatedCard { card: *card, index: i });
+            }
+        }
+        Ok(Hand::from_cards(cards.to_vec()))
+    }
+}
+
+/// Split `input` on whitespace, keeping the byte offset of each token.
+fn tokens_with_offsets(input: &str) -> impl Iterator<Item=(usize, &str)> {
+    input.split_whitespace()
+        .map(move |token| (token.as_ptr() as usize - input.as_ptr() as usize, token))
+}
+
 impl fmt::Display for Hand {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         let cards: String = self.sorted_cards.iter().map(|c| format!("{}", c)).collect();
@@ -223,6 +685,30 @@ impl fmt::Display for Hand {
     }
 }
 
+/// A hand given to `try_winning_hands` which could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidHand<'a> {
+    pub index: usize,
+    pub hand: &'a str,
+    pub error: PokerParseError,
+}
+
+impl fmt::Display for InvalidHand<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "hand #{} '{}': {}", self.index, self.hand, self.error)
+    }
+}
+
+impl Error for InvalidHand<'_> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Given a list of poker hands, return a list of those hands which win.
+///
+/// Note the type signature: this function should return _the same_ reference to
+/// the winning hand(s) as were passed in, not reconstructed strings which happen to be equal.
 pub fn winning_hands<'a>(hands: &[&'a str]) -> Vec<&'a str> {
     let ranked_hands = Vec::from_iter(hands.iter()
         .map(|h| (*h, Hand::from_string(h).ranking()))
@@ -235,4 +721,23 @@ pub fn winning_hands<'a>(hands: &[&'a str]) -> Vec<&'a str> {
         .collect();
 
     best_hands
-}
\ No newline at end of file
+}
+
+/// Same as `winning_hands` but reports the first hand which could not be parsed
+/// instead of panicking.
+pub fn try_winning_hands<'a>(hands: &[&'a str]) -> Result<Vec<&'a str>, InvalidHand<'a>> {
+    let mut ranked_hands = Vec::with_capacity(hands.len());
+    for (index, &hand) in hands.iter().enumerate() {
+        let parsed: Hand = hand.parse()
+            .map_err(|error| InvalidHand { index, hand, error })?;
+        ranked_hands.push((hand, parsed.ranking()));
+    }
+    let Some(top_ranking) = ranked_hands.iter().map(|(_h, r)| r).max().cloned() else {
+        return Ok(vec![]);
+    };
+
+    Ok(ranked_hands.into_iter()
+        .filter(|(_h, ranking)| *ranking == top_ranking)
+        .map(|(hand, _r)| hand)
+        .collect())
+}
@@ -0,0 +1,35 @@
+use crate::combinations;
+use crate::holdem::{keep_top_ranked, BestHand};
+use crate::Card;
+
+/// Pick the best Omaha hand: exactly two of the hole cards plus exactly three board cards.
+///
+/// Returns `None` when there are fewer than two hole cards or three board cards.
+pub fn best_omaha_hand(hole_cards: &[Card], board: &[Card]) -> Option<BestHand> {
+    omaha_combinations(hole_cards, board).into_iter()
+        .map(BestHand::from_five_cards)
+        .max_by(|a, b| a.ranking.cmp(&b.ranking))
+}
+
+/// Every legal Omaha hand: two of the hole cards followed by three of the board cards.
+pub fn omaha_combinations(hole_cards: &[Card], board: &[Card]) -> Vec<Vec<Card>> {
+    let board_combos = combinations(board, 3);
+
+    combinations(hole_cards, 2).into_iter()
+        .flat_map(|hole_pair| board_combos.iter()
+            .map(move |board_three| {
+                Vec::from_iter(hole_pair.iter().chain(board_three).copied())
+            })
+        )
+        .collect()
+}
+
+/// Given the board and each player's four hole cards, return the index and winning
+/// combination of the player(s) who win the showdown.
+pub fn omaha_winners(board: &[Card], players: &[[Card; 4]]) -> Vec<(usize, BestHand)> {
+    let best_hands = Vec::from_iter(players.iter()
+        .enumerate()
+        .filter_map(|(i, hole_cards)| best_omaha_hand(hole_cards, board).map(|best| (i, best)))
+    );
+    keep_top_ranked(best_hands)
+}
@@ -0,0 +1,153 @@
+//! Outs and draws of a Hold'em hand on the flop or the turn.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::deck::Deck;
+use crate::holdem::best_hand_from;
+use crate::stud::partial_ranking;
+use crate::{combinations, Card, Rank, RankingCategory, Suit};
+
+/// An unseen card improving the hand, and the category it improves to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Out {
+    pub card: Card,
+    pub category: RankingCategory,
+}
+
+/// A hand one card away from a flush or a straight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Draw {
+    /// Four cards of `suit`.
+    Flush(Suit),
+    /// Two ranks complete a straight, double gutshots included.
+    OpenEndedStraight,
+    /// A single rank completes a straight.
+    Gutshot,
+}
+
+/// What the hand is now and what the remaining cards can make of it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutsAnalysis {
+    pub current: RankingCategory,
+    /// The unseen cards which, coming next, make a better category. Cards only improving the
+    /// board, e.g. pairing it, are left out as they help every player alike.
+    pub outs: Vec<Out>,
+    pub draws: Vec<Draw>,
+    /// The chance of ending with a better category once the river is dealt.
+    pub improve_by_river: f64,
+    /// The chance of ending with each better category once the river is dealt.
+    pub odds_by_category: Vec<(RankingCategory, f64)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutsError {
+    WrongBoardSize(usize),
+    DuplicateCard(Card),
+}
+
+impl fmt::Display for OutsError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OutsError::WrongBoardSize(count) =>
+                write!(formatter, "outs need a flop or a turn, got {} board cards", count),
+            OutsError::DuplicateCard(card) =>
+                write!(formatter, "card {} is dealt more than once", card),
+        }
+    }
+}
+
+impl Error for OutsError {}
+
+/// Count the outs of `hole_cards` on a three or four card `board`, every card not in
+/// sight being taken as unseen.
+pub fn analyze_outs(hole_cards: &[Card; 2], board: &[Card]) -> Result<OutsAnalysis, OutsError> {
+    if !(3..=4).contains(&board.len()) {
+        return Err(OutsError::WrongBoardSize(board.len()));
+    }
+    let known_cards = Vec::from_iter(hole_cards.iter().chain(board).copied());
+    for (i, card) in known_cards.iter().enumerate() {
+        if known_cards[..i].contains(card) {
+            return Err(OutsError::DuplicateCard(*card));
+        }
+    }
+
+    let mut deck = Deck::new();
+    for card in known_cards.iter() {
+        deck.remove(card).unwrap();
+    }
+    let unseen = deck.cards().to_vec();
+    let category_with = |extra: &[Card]| {
+        let cards = Vec::from_iter(known_cards.iter().chain(extra).copied());
+        best_hand_from(&cards).unwrap().hand.ranking_category()
+    };
+    let current = category_with(&[]);
+
+    let outs = Vec::from_iter(unseen.iter()
+        .map(|&card| Out { card, category: category_with(&[card]) })
+        .filter(|out| out.category > current)
+        .filter(|out| board_category(&[board, &[out.card]].concat()) < out.category)
+    );
+
+    let run_outs = combinations(&unseen, 5 - board.len());
+    let mut improved_counts = vec![0_usize; RankingCategory::ALL.len()];
+    for run_out in run_outs.iter() {
+        let category = category_with(run_out);
+        if category > current {
+            improved_counts[category as usize] += 1;
+        }
+    }
+    let odds_by_category = Vec::from_iter(RankingCategory::ALL.into_iter()
+        .filter(|&category| improved_counts[category as usize] > 0)
+        .map(|category| (category, improved_counts[category as usize] as f64 / run_outs.len() as f64))
+    );
+    let improve_by_river = odds_by_category.iter().map(|(_category, odds)| odds).sum();
+
+    Ok(OutsAnalysis { current, outs, draws: draws(&known_cards), improve_by_river, odds_by_category })
+}
+
+/// The category of the board on its own, only pairs, trips and quads counting below five cards.
+fn board_category(board: &[Card]) -> RankingCategory {
+    match best_hand_from(board) {
+        Some(best) => best.hand.ranking_category(),
+        None => partial_ranking(board).category(),
+    }
+}
+
+/// The flush and straight draws among `cards`, none being reported for a made hand.
+pub fn draws(cards: &[Card]) -> Vec<Draw> {
+    let mut draws = vec![];
+    for suit in Suit::all() {
+        let suited = cards.iter().filter(|c| c.suit == suit).count();
+        if suited >= 5 {
+            return vec![];
+        }
+        if suited == 4 {
+            draws.push(Draw::Flush(suit));
+        }
+    }
+
+    let ranks = Vec::from_iter(cards.iter().map(|c| c.rank));
+    if makes_straight(&ranks) {
+        return draws;
+    }
+    let completing = Rank::all()
+        .filter(|rank| !ranks.contains(rank))
+        .filter(|&rank| makes_straight(&[ranks.as_slice(), &[rank]].concat()))
+        .count();
+    match completing {
+        0 => {}
+        1 => draws.push(Draw::Gutshot),
+        _ => draws.push(Draw::OpenEndedStraight),
+    }
+    draws
+}
+
+/// Whether five of the ranks are consecutive, an Ace also playing below the Two.
+fn makes_straight(ranks: &[Rank]) -> bool {
+    let mut values = Vec::from_iter(ranks.iter().map(|rank| rank.value()));
+    if ranks.contains(&Rank::Ace) {
+        values.push(1);
+    }
+    (1..=10).any(|low| (low..low + 5).all(|value| values.contains(&value)))
+}
@@ -0,0 +1,197 @@
+//! Starting-hand ranges in the usual shorthand, e.g. `"AKs, TT+, A5s-A2s, KQo"`.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::holdem::{best_hand_from, BestHand};
+use crate::{Card, Rank, Suit};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeError {
+    EmptyRange,
+    BadToken { token: String, offset: usize },
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RangeError::EmptyRange =>
+                write!(formatter, "the range is empty"),
+            RangeError::BadToken { token, offset } =>
+                write!(formatter, "invalid range token '{}' at byte {}", token, offset),
+        }
+    }
+}
+
+impl Error for RangeError {}
+
+/// A set of two-card combos, each one listed once with its higher card first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Range {
+    combos: Vec<[Card; 2]>,
+}
+
+impl Range {
+    pub fn combos(&self) -> &[[Card; 2]] {
+        &self.combos
+    }
+
+    /// The number of combos, e.g. 6 for a pair, 4 for a suited and 12 for an offsuit hand.
+    pub fn len(&self) -> usize {
+        self.combos.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.combos.is_empty()
+    }
+
+    /// Whether the range holds these two cards, in any order.
+    pub fn contains(&self, cards: &[Card; 2]) -> bool {
+        self.combos.iter()
+            .any(|combo| combo == cards || (combo[0] == cards[1] && combo[1] == cards[0]))
+    }
+
+    /// Drop the combos using any of the `dead` cards, e.g. our hole cards or the board.
+    pub fn remove_blocked(&mut self, dead: &[Card]) {
+        self.combos.retain(|combo| !combo.iter().any(|card| dead.contains(card)));
+    }
+
+    /// The best hand each combo makes with the `board`, skipping the combos it blocks.
+    pub fn evaluate(&self, board: &[Card]) -> Vec<([Card; 2], BestHand)> {
+        self.combos.iter()
+            .filter(|combo| !combo.iter().any(|card| board.contains(card)))
+            .filter_map(|combo| {
+                let cards = Vec::from_iter(combo.iter().chain(board).copied());
+                best_hand_from(&cards).map(|best| (*combo, best))
+            })
+            .collect()
+    }
+
+    fn add(&mut self, combo: [Card; 2]) {
+        if !self.contains(&combo) {
+            self.combos.push(combo);
+        }
+    }
+}
+
+impl FromStr for Range {
+    type Err = RangeError;
+
+    /// Comma-separated hands: pairs (`"TT"`), suited or offsuit hands (`"AKs"`, `"KQo"`),
+    /// both at once (`"AK"`), and upwards (`"TT+"`, `"ATs+"`) or bounded (`"A5s-A2s"`) spans.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut range = Range::default();
+        let mut offset = 0;
+        for part in input.split(',') {
+            let token = part.trim();
+            let token_offset = offset + part.len() - part.trim_start().len();
+            offset += part.len() + 1;
+            if token.is_empty() {
+                continue;
+            }
+
+            let classes = expand(token)
+                .ok_or_else(|| RangeError::BadToken { token: token.to_string(), offset: token_offset })?;
+            for class in classes {
+                class.combos().into_iter().for_each(|combo| range.add(combo));
+            }
+        }
+
+        if range.is_empty() {
+            return Err(RangeError::EmptyRange);
+        }
+        Ok(range)
+    }
+}
+
+impl TryFrom<&str> for Range {
+    type Error = RangeError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        input.parse()
+    }
+}
+
+/// A starting hand regardless of suits, such as `AKs`. `suited` is `None` for pairs
+/// and for hands taken both suited and offsuit.
+#[derive(Debug, Clone, Copy)]
+struct HandClass {
+    high: Rank,
+    low: Rank,
+    suited: Option<bool>,
+}
+
+impl HandClass {
+    fn parse(text: &str) -> Option<Self> {
+        let mut chars = text.chars();
+        let first = Rank::try_from(chars.next()?.to_ascii_uppercase()).ok()?;
+        let second = Rank::try_from(chars.next()?.to_ascii_uppercase()).ok()?;
+        let suited = match chars.next() {
+            None => None,
+            Some('s' | 'S') => Some(true),
+            Some('o' | 'O') => Some(false),
+            Some(_) => return None,
+        };
+        if chars.next().is_some() || (first == second && suited.is_some()) {
+            return None;
+        }
+        Some(HandClass { high: first.max(second), low: first.min(second), suited })
+    }
+
+    fn is_pair(&self) -> bool {
+        self.high == self.low
+    }
+
+    fn combos(&self) -> Vec<[Card; 2]> {
+        Vec::from_iter(Suit::all()
+            .flat_map(|first| Suit::all().map(move |second| (first, second)))
+            .filter(|&(first, second)| match (self.is_pair(), self.suited) {
+                (true, _) => first > second,
+                (false, Some(true)) => first == second,
+                (false, Some(false)) => first != second,
+                (false, None) => true,
+            })
+            .map(|(first, second)| [Card::new(self.high, first), Card::new(self.low, second)])
+        )
+    }
+}
+
+/// The hand classes a single token stands for, `None` if it is not valid notation.
+fn expand(token: &str) -> Option<Vec<HandClass>> {
+    if let Some(base) = token.strip_suffix('+') {
+        let class = HandClass::parse(base)?;
+        if class.is_pair() {
+            return Some(pairs_between(class.low, Rank::Ace));
+        }
+        return Some(Vec::from_iter(Rank::all()
+            .filter(|&rank| rank >= class.low && rank < class.high)
+            .map(|low| HandClass { low, ..class })
+        ));
+    }
+
+    if let Some((from, to)) = token.split_once('-') {
+        let from = HandClass::parse(from.trim())?;
+        let to = HandClass::parse(to.trim())?;
+        if from.is_pair() && to.is_pair() {
+            return Some(pairs_between(from.low.min(to.low), from.low.max(to.low)));
+        }
+        if from.is_pair() || to.is_pair() || from.high != to.high || from.suited != to.suited {
+            return None;
+        }
+        let (bottom, top) = (from.low.min(to.low), from.low.max(to.low));
+        return Some(Vec::from_iter(Rank::all()
+            .filter(|&rank| rank >= bottom && rank <= top)
+            .map(|low| HandClass { low, ..from })
+        ));
+    }
+
+    HandClass::parse(token).map(|class| vec![class])
+}
+
+fn pairs_between(bottom: Rank, top: Rank) -> Vec<HandClass> {
+    Vec::from_iter(Rank::all()
+        .filter(|&rank| rank >= bottom && rank <= top)
+        .map(|rank| HandClass { high: rank, low: rank, suited: None })
+    )
+}
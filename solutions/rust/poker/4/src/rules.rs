@@ -0,0 +1,86 @@
+//! Ranking rules for high and lowball games.
+
+use crate::{Hand, InvalidHand, RankingCategory};
+
+/// How hands are ranked against each other.
+pub trait RankingRules {
+    /// Score of a hand under these rules, the higher the better.
+    fn score(&self, hand: &Hand) -> Vec<u8>;
+}
+
+/// Regular high hands, as ordered by `Hand::ranking`, following the hand's `Variant`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct High;
+
+/// Ace-to-Five lowball (Razz): Aces are low, straights and flushes don't count,
+/// the best hand being 5-4-3-2-A.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AceToFiveLow;
+
+/// Deuce-to-Seven lowball: Aces are high, straights and flushes count against
+/// the hand, the best one being 7-5-4-3-2 offsuit.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeuceToSevenLow;
+
+impl RankingRules for High {
+    fn score(&self, hand: &Hand) -> Vec<u8> {
+        let ranking = hand.ranking();
+        let mut score = vec![ranking.strength];
+        score.extend(ranking.values);
+        score
+    }
+}
+
+impl RankingRules for AceToFiveLow {
+    fn score(&self, hand: &Hand) -> Vec<u8> {
+        let groups = hand.grouped_values(1);
+        let pairing = match (groups[0].0, groups.get(1).map(|g| g.0)) {
+            (4, _) => RankingCategory::Quads,
+            (3, Some(2)) => RankingCategory::Full,
+            (3, _) => RankingCategory::Trips,
+            (2, Some(2)) => RankingCategory::TwoPair,
+            (2, _) => RankingCategory::OnePair,
+            _ => RankingCategory::HighestCard,
+        };
+        lowest_is_best(pairing, &groups)
+    }
+}
+
+impl RankingRules for DeuceToSevenLow {
+    fn score(&self, hand: &Hand) -> Vec<u8> {
+        let category = match hand.ranking_category() {
+            // The wheel is no straight when Aces only play high.
+            RankingCategory::Straight if hand.ace_low => RankingCategory::HighestCard,
+            RankingCategory::StraightFlush if hand.ace_low => RankingCategory::Flush,
+            category => category,
+        };
+        lowest_is_best(category, &hand.grouped_values(14))
+    }
+}
+
+/// Turn the category and grouped ranks of a low hand into a score where higher is better.
+fn lowest_is_best(category: RankingCategory, groups: &[(u8, u8)]) -> Vec<u8> {
+    let mut score = vec![u8::MAX - category as u8];
+    score.extend(groups.iter().map(|&(_count, value)| u8::MAX - value));
+    score
+}
+
+/// Same as `try_winning_hands`, hands being ranked following `rules`.
+pub fn winning_hands_with<'a, R>(hands: &[&'a str], rules: &R) -> Result<Vec<&'a str>, InvalidHand<'a>>
+    where R: RankingRules + ?Sized
+{
+    let mut scored_hands = Vec::with_capacity(hands.len());
+    for (index, &hand) in hands.iter().enumerate() {
+        let parsed: Hand = hand.parse()
+            .map_err(|error| InvalidHand { index, hand, error })?;
+        scored_hands.push((hand, rules.score(&parsed)));
+    }
+    let Some(top_score) = scored_hands.iter().map(|(_h, s)| s).max().cloned() else {
+        return Ok(vec![]);
+    };
+
+    Ok(scored_hands.into_iter()
+        .filter(|(_h, score)| *score == top_score)
+        .map(|(hand, _s)| hand)
+        .collect())
+}
@@ -0,0 +1,55 @@
+//! Serialization behind the `serde` feature: cards in their compact notation (`"10H"`),
+//! hands as arrays of cards and categories as stable snake_case strings.
+//!
+//! Only hands of the standard variant serialize, the array of cards not telling the variant.
+
+use serde::de::Error as DeError;
+use serde::ser::Error as SerError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::variant::Variant;
+use crate::{Card, Hand, RankingCategory};
+
+impl Serialize for Card {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.notation())
+    }
+}
+
+impl<'de> Deserialize<'de> for Card {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let notation = String::deserialize(deserializer)?;
+        notation.parse().map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for Hand {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.variant != Variant::standard() {
+            return Err(S::Error::custom("only hands of the standard variant can be serialized"));
+        }
+        self.cards().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Hand {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let cards = Vec::<Card>::deserialize(deserializer)?;
+        Hand::try_from(cards.as_slice()).map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for RankingCategory {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for RankingCategory {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        RankingCategory::ALL.into_iter()
+            .find(|category| category.id() == name)
+            .ok_or_else(|| D::Error::custom(format!("unknown ranking category '{}'", name)))
+    }
+}
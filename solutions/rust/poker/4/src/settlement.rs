@@ -0,0 +1,134 @@
+//! Pay the pot out: main and side pots, split pots and odd chips.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::Hand;
+
+/// What a player put in the pot and what they show down.
+#[derive(Debug, Clone)]
+pub struct Stake {
+    pub seat: usize,
+    pub contribution: u64,
+    pub all_in: bool,
+    pub folded: bool,
+    /// Only needed when the player contests a pot at showdown.
+    pub hand: Option<Hand>,
+}
+
+/// A main or side pot and the players it goes to, given by their index in the stakes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pot {
+    pub amount: u64,
+    pub eligible: Vec<usize>,
+    pub winners: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settlement {
+    /// The main pot first, then the side pots.
+    pub pots: Vec<Pot>,
+    /// What each player gets back, in the order of the stakes.
+    pub payouts: Vec<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SettlementError {
+    EveryoneFolded,
+    MissingHand { seat: usize },
+    ShortContribution { seat: usize },
+}
+
+impl fmt::Display for SettlementError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SettlementError::EveryoneFolded =>
+                write!(formatter, "every player folded"),
+            SettlementError::MissingHand { seat } =>
+                write!(formatter, "player at seat {} contests a pot without a hand", seat),
+            SettlementError::ShortContribution { seat } =>
+                write!(formatter, "player at seat {} put less than the others without being all-in", seat),
+        }
+    }
+}
+
+impl Error for SettlementError {}
+
+/// Build the main and side pots out of the stakes and pay them to their best hands.
+///
+/// Tied winners split a pot evenly, the odd chips going one by one to the winners
+/// seated first to the left of the `button` seat.
+pub fn settle(stakes: &[Stake], button: usize) -> Result<Settlement, SettlementError> {
+    let in_hand = Vec::from_iter((0..stakes.len()).filter(|&i| !stakes[i].folded));
+    if in_hand.is_empty() {
+        return Err(SettlementError::EveryoneFolded);
+    }
+    let top_contribution = in_hand.iter().map(|&i| stakes[i].contribution).max().unwrap();
+    if let Some(&short) = in_hand.iter()
+        .find(|&&i| !stakes[i].all_in && stakes[i].contribution < top_contribution) {
+        return Err(SettlementError::ShortContribution { seat: stakes[short].seat });
+    }
+
+    // Each distinct contribution of a player still in the hand caps a pot.
+    let mut levels = Vec::from_iter(in_hand.iter().map(|&i| stakes[i].contribution));
+    levels.sort_unstable();
+    levels.dedup();
+
+    let mut pots: Vec<Pot> = vec![];
+    let mut previous_level = 0;
+    for &level in levels.iter() {
+        let amount: u64 = stakes.iter()
+            .map(|s| s.contribution.min(level) - s.contribution.min(previous_level))
+            .sum();
+        let eligible = Vec::from_iter(in_hand.iter().copied()
+            .filter(|&i| stakes[i].contribution >= level)
+        );
+        pots.push(Pot { amount, eligible, winners: vec![] });
+        previous_level = level;
+    }
+    // Folded players may have put in more than anyone left, it goes to the last pot.
+    let dead_money: u64 = stakes.iter()
+        .map(|s| s.contribution.saturating_sub(previous_level))
+        .sum();
+    pots.last_mut().unwrap().amount += dead_money;
+    pots.retain(|pot| pot.amount > 0);
+
+    let mut payouts = vec![0; stakes.len()];
+    for pot in pots.iter_mut() {
+        pot.winners = pot_winners(stakes, &pot.eligible)?;
+        sort_from_button(&mut pot.winners, stakes, button);
+
+        let share = pot.amount / pot.winners.len() as u64;
+        let odd_chips = (pot.amount % pot.winners.len() as u64) as usize;
+        for (n, &winner) in pot.winners.iter().enumerate() {
+            payouts[winner] += share + if n < odd_chips { 1 } else { 0 };
+        }
+    }
+    Ok(Settlement { pots, payouts })
+}
+
+fn pot_winners(stakes: &[Stake], eligible: &[usize]) -> Result<Vec<usize>, SettlementError> {
+    if let [single] = eligible {
+        return Ok(vec![*single]);
+    }
+    let mut rankings = Vec::with_capacity(eligible.len());
+    for &i in eligible {
+        let hand = stakes[i].hand.as_ref()
+            .ok_or(SettlementError::MissingHand { seat: stakes[i].seat })?;
+        rankings.push((i, hand.ranking()));
+    }
+    let top_ranking = rankings.iter().map(|(_i, r)| r).max().cloned().unwrap();
+
+    Ok(rankings.into_iter()
+        .filter(|(_i, ranking)| *ranking == top_ranking)
+        .map(|(i, _r)| i)
+        .collect())
+}
+
+/// Order players clockwise starting from the seat left of the button.
+fn sort_from_button(players: &mut [usize], stakes: &[Stake], button: usize) {
+    players.sort_by_key(|&i| {
+        let seat = stakes[i].seat;
+        (seat <= button, seat)
+    });
+}
@@ -0,0 +1,216 @@
+//! Seven-card stud and Razz: dealing up and down cards, the bring-in and who acts first.
+
+use crate::deck::Deck;
+use crate::game::GameError;
+use crate::rules::{AceToFiveLow, High, RankingRules};
+use crate::{combinations, Card, Hand, HandRank, Rank};
+use crate::RankingCategory::*;
+
+/// The stud games, played for the best high hand or the best Ace-to-Five low.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StudVariant {
+    SevenCardStud,
+    Razz,
+}
+
+/// The cards of one player, only the `up` ones being seen by the others.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StudHand {
+    pub down: Vec<Card>,
+    pub up: Vec<Card>,
+}
+
+impl StudHand {
+    /// All the cards, down cards first.
+    pub fn cards(&self) -> Vec<Card> {
+        Vec::from_iter(self.down.iter().chain(&self.up).copied())
+    }
+}
+
+/// A stud hand being dealt, seats being numbered from the dealer's left.
+#[derive(Debug, Clone)]
+pub struct StudDeal {
+    variant: StudVariant,
+    deck: Deck,
+    hands: Vec<StudHand>,
+    folded: Vec<bool>,
+    community: Option<Card>,
+}
+
+impl StudDeal {
+    /// Deal third street, two down cards and one up card to each of the 2 to 8 players.
+    pub fn new(variant: StudVariant, players: usize, deck: Deck) -> Result<Self, GameError> {
+        if players < 2 {
+            return Err(GameError::NotEnoughPlayers);
+        }
+        if players > 8 {
+            return Err(GameError::TooManyPlayers { max: 8 });
+        }
+        let mut deal = StudDeal {
+            variant,
+            deck,
+            hands: vec![StudHand::default(); players],
+            folded: vec![false; players],
+            community: None,
+        };
+        deal.deal_round(false)?;
+        deal.deal_round(false)?;
+        deal.deal_round(true)?;
+        Ok(deal)
+    }
+
+    pub fn variant(&self) -> StudVariant {
+        self.variant
+    }
+
+    pub fn hands(&self) -> &[StudHand] {
+        &self.hands
+    }
+
+    /// The card dealt face up to everyone on the river when too few were left to go round.
+    pub fn community_card(&self) -> Option<Card> {
+        self.community
+    }
+
+    /// How many cards the players still in have, from 3 on third street to 7 on the river.
+    pub fn cards_dealt(&self) -> usize {
+        self.active_seats()
+            .map(|seat| self.hands[seat].down.len() + self.hands[seat].up.len())
+            .max()
+            .map_or(0, |dealt| dealt + usize::from(self.community.is_some()))
+    }
+
+    pub fn has_folded(&self, seat: usize) -> bool {
+        self.folded[seat]
+    }
+
+    pub fn fold(&mut self, seat: usize) {
+        self.folded[seat] = true;
+    }
+
+    /// Deal the next street: an up card on fourth to sixth street, a down card on the river.
+    /// When the deck cannot go round on the river, as with eight players, a single community
+    /// card is dealt face up instead.
+    pub fn deal_next_street(&mut self) -> Result<(), GameError> {
+        match self.cards_dealt() {
+            3..=5 => self.deal_round(true),
+            6 if self.deck.len() < self.active_seats().count() => {
+                self.community = Some(self.deck.deal()?);
+                Ok(())
+            }
+            6 => self.deal_round(false),
+            _ => Err(GameError::HandOver),
+        }
+    }
+
+    /// The player forced to open on third street: the lowest up card in stud, the highest
+    /// in Razz, suits (clubs, diamonds, hearts then spades) breaking ties the same way.
+    pub fn bring_in(&self) -> usize {
+        let door_cards = self.active_seats().map(|seat| (seat, self.hands[seat].up[0]));
+        match self.variant {
+            StudVariant::SevenCardStud => door_cards
+                .min_by_key(|&(_seat, card)| (card.rank, card.suit))
+                .unwrap().0,
+            StudVariant::Razz => door_cards
+                .max_by_key(|&(_seat, card)| (low_value(card.rank), card.suit))
+                .unwrap().0,
+        }
+    }
+
+    /// The player acting first on the current street: the bring-in on third street, then the
+    /// best visible hand (highest in stud, lowest in Razz), the earliest seat on ties.
+    pub fn first_to_act(&self) -> usize {
+        if self.cards_dealt() <= 3 {
+            return self.bring_in();
+        }
+        let visible = Vec::from_iter(self.active_seats().map(|seat| (seat, self.visible_ranking(seat))));
+        let best = match self.variant {
+            StudVariant::SevenCardStud => visible.iter().map(|(_seat, ranking)| ranking).max(),
+            StudVariant::Razz => visible.iter().map(|(_seat, ranking)| ranking).min(),
+        }.unwrap();
+        visible.iter().find(|(_seat, ranking)| ranking == best).unwrap().0
+    }
+
+    /// The ranking of what the other players see of `seat`'s hand.
+    pub fn visible_ranking(&self, seat: usize) -> HandRank {
+        match self.variant {
+            StudVariant::SevenCardStud => partial_ranking(&self.hands[seat].up),
+            StudVariant::Razz => partial_low_ranking(&self.hands[seat].up),
+        }
+    }
+
+    /// The players still in whose best five cards win the showdown.
+    pub fn winners(&self) -> Vec<usize> {
+        let scored = Vec::from_iter(self.active_seats().map(|seat| {
+            let mut cards = self.hands[seat].cards();
+            cards.extend(self.community);
+            let best = combinations(&cards, 5).into_iter()
+                .map(|five_cards| {
+                    let hand = Hand::from_cards(five_cards);
+                    match self.variant {
+                        StudVariant::SevenCardStud => High.score(&hand),
+                        StudVariant::Razz => AceToFiveLow.score(&hand),
+                    }
+                })
+                .max()
+                .unwrap_or_default();
+            (seat, best)
+        }));
+        let Some(top_score) = scored.iter().map(|(_seat, score)| score).max() else {
+            return vec![];
+        };
+
+        Vec::from_iter(scored.iter()
+            .filter(|(_seat, score)| score == top_score)
+            .map(|(seat, _score)| *seat))
+    }
+
+    fn active_seats(&self) -> impl Iterator<Item=usize> + '_ {
+        (0..self.hands.len()).filter(|&seat| !self.folded[seat])
+    }
+
+    fn deal_round(&mut self, face_up: bool) -> Result<(), GameError> {
+        let seats = Vec::from_iter(self.active_seats());
+        let cards = self.deck.deal_many(seats.len())?;
+        for (seat, card) in seats.into_iter().zip(cards) {
+            let hand = &mut self.hands[seat];
+            if face_up { hand.up.push(card) } else { hand.down.push(card) }
+        }
+        Ok(())
+    }
+}
+
+/// Rank one to four cards for a high game. Only pairs, two pair, trips and quads count,
+/// then the highest ranks.
+pub fn partial_ranking(cards: &[Card]) -> HandRank {
+    visible_ranking(cards, Rank::value)
+}
+
+/// Rank one to four cards for Razz, Aces counting as 1. The lowest `HandRank` is the best.
+pub fn partial_low_ranking(cards: &[Card]) -> HandRank {
+    visible_ranking(cards, low_value)
+}
+
+fn low_value(rank: Rank) -> u8 {
+    if rank == Rank::Ace { 1 } else { rank.value() }
+}
+
+fn visible_ranking(cards: &[Card], value: fn(Rank) -> u8) -> HandRank {
+    let mut groups = Vec::from_iter(Hand::group_cards_by_rank(cards).into_iter()
+        .map(|(rank, cards)| (cards.len() as u8, value(rank)))
+    );
+    groups.sort_unstable_by(|a, b| b.cmp(a));
+    let category = match (groups.first().map(|g| g.0), groups.get(1).map(|g| g.0)) {
+        (Some(4), _) => Quads,
+        (Some(3), _) => Trips,
+        (Some(2), Some(2)) => TwoPair,
+        (Some(2), _) => OnePair,
+        _ => HighestCard,
+    };
+
+    HandRank {
+        strength: category as u8,
+        category,
+        values: Vec::from_iter(groups.into_iter().map(|(_count, value)| value)),
+    }
+}
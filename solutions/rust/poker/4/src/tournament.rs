@@ -0,0 +1,182 @@
+//! Tournament structure: ICM equity of the stacks, blind levels and chip-ups.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::deck::{Deck, DeckError};
+use crate::game::Blinds;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TournamentError {
+    NoChipsInPlay,
+    TooManyPlayers(usize),
+    TooManyPlaces { players: usize, places: usize },
+    NoBlindLevels,
+    BadDenominations { small: u64, large: u64 },
+    Deck(DeckError),
+}
+
+impl fmt::Display for TournamentError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TournamentError::NoChipsInPlay =>
+                write!(formatter, "no player has any chips"),
+            TournamentError::TooManyPlayers(count) =>
+                write!(formatter, "at most 64 players are supported, got {}", count),
+            TournamentError::TooManyPlaces { players, places } =>
+                write!(formatter, "{} players with {} paid places are too many to work out", players, places),
+            TournamentError::NoBlindLevels =>
+                write!(formatter, "a blind schedule needs at least one level"),
+            TournamentError::BadDenominations { small, large } =>
+                write!(formatter, "chips worth {} cannot be coloured up into chips worth {}", small, large),
+            TournamentError::Deck(error) => write!(formatter, "{}", error),
+        }
+    }
+}
+
+impl Error for TournamentError {}
+
+impl From<DeckError> for TournamentError {
+    fn from(error: DeckError) -> Self {
+        TournamentError::Deck(error)
+    }
+}
+
+/// Each player's share of the prize pool under the Independent Chip Model: the chance of
+/// finishing first is the share of the chips, then the same goes for the next places among
+/// the players left.
+///
+/// `payouts` lists the prizes from first place down. Players without chips get nothing.
+///
+/// Every set of players who may fill the paid places above the last one is tracked, so the
+/// work grows quickly with both counts: at most `MAX_ICM_PLACED_SETS` such sets are allowed,
+/// e.g. 20 players with 8 paid places, or 64 players with 4.
+pub fn icm_equity(stacks: &[u64], payouts: &[f64]) -> Result<Vec<f64>, TournamentError> {
+    if stacks.len() > 64 {
+        return Err(TournamentError::TooManyPlayers(stacks.len()));
+    }
+    let total_chips: u64 = stacks.iter().sum();
+    if total_chips == 0 {
+        return Err(TournamentError::NoChipsInPlay);
+    }
+    let in_play = Vec::from_iter((0..stacks.len()).filter(|&i| stacks[i] > 0));
+    let places = payouts.len().min(in_play.len());
+    // The largest level of the memo holds the sets of up to `places - 1` players.
+    let deepest = places.saturating_sub(1).min(in_play.len() / 2);
+    if binomial(in_play.len(), deepest) > MAX_ICM_PLACED_SETS {
+        return Err(TournamentError::TooManyPlaces { players: in_play.len(), places });
+    }
+
+    let mut equities = vec![0.0; stacks.len()];
+    // The chance of each set of players, as a bit mask, having taken the top places.
+    let mut placed: HashMap<u64, f64> = HashMap::from([(0, 1.0)]);
+    for (place, &prize) in payouts.iter().take(places).enumerate() {
+        // Nobody reads the sets after the last paid place, so they are not built.
+        let last_place = place + 1 == places;
+        let mut next_placed: HashMap<u64, f64> = HashMap::new();
+        for (&mask, &probability) in placed.iter() {
+            let chips_left: u64 = in_play.iter()
+                .filter(|&&i| mask & (1 << i) == 0)
+                .map(|&i| stacks[i])
+                .sum();
+            for &i in in_play.iter().filter(|&&i| mask & (1 << i) == 0) {
+                let finishing_here = probability * stacks[i] as f64 / chips_left as f64;
+                equities[i] += finishing_here * prize;
+                if !last_place {
+                    *next_placed.entry(mask | (1 << i)).or_default() += finishing_here;
+                }
+            }
+        }
+        placed = next_placed;
+    }
+    Ok(equities)
+}
+
+/// How many sets of placed players `icm_equity` tracks at most for one place.
+pub const MAX_ICM_PLACED_SETS: u64 = 100_000;
+
+fn binomial(n: usize, k: usize) -> u64 {
+    // Exact at each step, and C(64, 32) times 64 still fits.
+    (0..k).fold(1_u128, |result, i| result * (n - i) as u128 / (i + 1) as u128) as u64
+}
+
+/// One level of a tournament structure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlindLevel {
+    pub blinds: Blinds,
+    pub ante: u64,
+    pub minutes: u64,
+}
+
+/// The blind levels of a tournament, the last one lasting until the end.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlindSchedule {
+    levels: Vec<BlindLevel>,
+}
+
+impl BlindSchedule {
+    pub fn new(levels: Vec<BlindLevel>) -> Result<Self, TournamentError> {
+        if levels.is_empty() {
+            return Err(TournamentError::NoBlindLevels);
+        }
+        Ok(BlindSchedule { levels })
+    }
+
+    pub fn levels(&self) -> &[BlindLevel] {
+        &self.levels
+    }
+
+    /// The index of the level being played `elapsed_minutes` into the tournament.
+    pub fn level_index_at(&self, elapsed_minutes: u64) -> usize {
+        let mut level_end = 0;
+        for (index, level) in self.levels.iter().enumerate() {
+            level_end += level.minutes;
+            if elapsed_minutes < level_end {
+                return index;
+            }
+        }
+        self.levels.len() - 1
+    }
+
+    pub fn level_at(&self, elapsed_minutes: u64) -> &BlindLevel {
+        &self.levels[self.level_index_at(elapsed_minutes)]
+    }
+}
+
+/// Colour up chips worth `small` into chips worth `large`, given how many small chips each
+/// player has. Whole sets are exchanged, then the odd chips are raced off: one card is dealt
+/// per odd chip, and the players with the highest cards win one large chip each, as many
+/// large chips being handed out as the odd chips are worth, rounded to the nearest.
+///
+/// Returns the large chips each player ends up with in exchange of their small chips, or an
+/// error when the deck is too short to deal a card for every odd chip.
+// `u64::is_multiple_of` is too recent for the compilers the crate builds with.
+#[allow(clippy::manual_is_multiple_of)]
+pub fn chip_up(small_chips: &[u64], small: u64, large: u64, deck: &mut Deck) -> Result<Vec<u64>, TournamentError> {
+    if small == 0 || large <= small || large % small != 0 {
+        return Err(TournamentError::BadDenominations { small, large });
+    }
+    let per_large = large / small;
+    let mut large_chips = Vec::from_iter(small_chips.iter().map(|&chips| chips / per_large));
+    let odd_chips = Vec::from_iter(small_chips.iter().map(|&chips| chips % per_large));
+
+    let odd_total: u64 = odd_chips.iter().sum();
+    let to_award = ((odd_total + per_large / 2) / per_large) as usize;
+    if odd_total > deck.len() as u64 {
+        return Err(DeckError::NotEnoughCards { needed: odd_total as usize, left: deck.len() }.into());
+    }
+    let mut best_cards = Vec::with_capacity(small_chips.len());
+    for (player, &odd) in odd_chips.iter().enumerate() {
+        if odd > 0 {
+            let best = deck.deal_many(odd as usize)?.into_iter().max().unwrap();
+            best_cards.push((best, player));
+        }
+    }
+    // Cards order by rank, then by suit.
+    best_cards.sort_by(|a, b| b.cmp(a));
+    for &(_card, player) in best_cards.iter().take(to_award) {
+        large_chips[player] += 1;
+    }
+    Ok(large_chips)
+}
@@ -0,0 +1,108 @@
+//! Deck compositions and category orders other than the regular 52-card game,
+//! such as short-deck (6+) Hold'em.
+
+use crate::holdem::BestHand;
+use crate::{combinations, Card, Hand, InvalidHand, Rank, RankingCategory, Suit};
+use crate::RankingCategory::*;
+
+/// Which cards are in play and how the categories rank against each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Variant {
+    /// The lowest rank in the deck, an Ace playing just below it in the lowest straight.
+    pub lowest_rank: Rank,
+    /// How many copies of every card the deck holds.
+    pub decks: u8,
+    /// Every category, from the weakest to the strongest.
+    category_order: [RankingCategory; 10],
+}
+
+impl Variant {
+    /// The regular 52-card deck, A-2-3-4-5 being the lowest straight.
+    pub const fn standard() -> Self {
+        Variant { lowest_rank: Rank::Two, decks: 1, category_order: RankingCategory::ALL }
+    }
+
+    /// Short-deck (6+): Twos to Fives removed, A-6-7-8-9 being the lowest straight,
+    /// and a flush beating a full house.
+    pub const fn short_deck() -> Self {
+        Variant {
+            lowest_rank: Rank::Six,
+            decks: 1,
+            category_order: [
+                HighestCard, OnePair, TwoPair, Trips, Straight,
+                Full, Flush, Quads, StraightFlush, FiveOfAKind,
+            ],
+        }
+    }
+
+    /// The same variant played with several decks shuffled together.
+    pub const fn with_decks(self, decks: u8) -> Self {
+        Variant { decks, ..self }
+    }
+
+    /// The same variant with the categories ranking in `order`, weakest first, or `None`
+    /// unless `order` lists every category exactly once.
+    pub fn with_category_order(self, order: [RankingCategory; 10]) -> Option<Self> {
+        let every_category = RankingCategory::ALL.iter().all(|category| order.contains(category));
+        every_category.then_some(Variant { category_order: order, ..self })
+    }
+
+    /// Every category, from the weakest to the strongest.
+    pub fn category_order(&self) -> [RankingCategory; 10] {
+        self.category_order
+    }
+
+    /// The ranks in the deck, lowest first.
+    pub fn ranks(&self) -> impl DoubleEndedIterator<Item=Rank> {
+        let lowest_rank = self.lowest_rank;
+        Rank::all().filter(move |&rank| rank >= lowest_rank)
+    }
+
+    /// Every card of the deck, as many times as there are decks.
+    pub fn cards(&self) -> Vec<Card> {
+        let single_deck = Vec::from_iter(Suit::all()
+            .flat_map(|suit| self.ranks().map(move |rank| Card::new(rank, suit)))
+        );
+        single_deck.repeat(self.decks as usize)
+    }
+
+    /// Where `category` stands in this variant, 0 being the weakest.
+    pub fn strength(&self, category: RankingCategory) -> u8 {
+        // Every category is in the order, `with_category_order` making sure of it.
+        self.category_order.iter().position(|&c| c == category).unwrap() as u8
+    }
+
+    /// Pick the best five-card `Hand` out of `cards`, `None` when fewer than five are given.
+    pub fn best_hand(&self, cards: &[Card]) -> Option<BestHand> {
+        combinations(cards, 5).into_iter()
+            .map(|five_cards| {
+                let hand = Hand::from_cards_in(five_cards.clone(), *self);
+                BestHand { ranking: hand.ranking(), hand, cards: five_cards }
+            })
+            .max_by(|a, b| a.ranking.cmp(&b.ranking))
+    }
+}
+
+impl Default for Variant {
+    fn default() -> Self {
+        Variant::standard()
+    }
+}
+
+/// Same as `try_winning_hands`, hands being parsed and ranked following `variant`.
+pub fn winning_hands_in<'a>(hands: &[&'a str], variant: &Variant) -> Result<Vec<&'a str>, InvalidHand<'a>> {
+    let mut ranked_hands = Vec::with_capacity(hands.len());
+    for (index, &hand) in hands.iter().enumerate() {
+        let parsed = Hand::parse_in(hand, variant)
+            .map_err(|error| InvalidHand { index, hand, error })?;
+        ranked_hands.push((hand, parsed.ranking()));
+    }
+    let Some(top_ranking) = ranked_hands.iter().map(|(_h, r)| r).max().cloned() else {
+        return Ok(vec![]);
+    };
+
+    Ok(ranked_hands.into_iter()
+        .filter(|(_h, ranking)| *ranking == top_ranking)
+        .map(|(hand, _r)| hand)
+        .collect())
+}
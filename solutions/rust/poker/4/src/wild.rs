@@ -0,0 +1,212 @@
+//! Wild cards: jokers and designated wild ranks (e.g. deuces wild).
+//!
+//! Wild cards are substituted with whatever makes the best hand, duplicates of the
+//! natural cards included, which makes five of a kind the top category.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Card, InvalidHand, PokerParseError, Rank, RankingCategory, Suit};
+use crate::RankingCategory::*;
+
+/// A card of a deck with jokers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WildCard {
+    Natural(Card),
+    Joker,
+}
+
+impl FromStr for WildCard {
+    type Err = PokerParseError;
+
+    /// A joker is written `JK` or `*`, any other token being parsed as a `Card`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "JK" | "*" => Ok(WildCard::Joker),
+            _ => input.parse().map(WildCard::Natural),
+        }
+    }
+}
+
+impl fmt::Display for WildCard {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WildCard::Natural(card) => card.fmt(formatter),
+            WildCard::Joker => write!(formatter, "[JK]"),
+        }
+    }
+}
+
+/// Which cards are wild, jokers always being.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WildRules {
+    pub wild_ranks: Vec<Rank>,
+}
+
+impl WildRules {
+    pub fn jokers_only() -> Self {
+        WildRules { wild_ranks: vec![] }
+    }
+
+    pub fn deuces_wild() -> Self {
+        WildRules { wild_ranks: vec![Rank::Two] }
+    }
+
+    pub fn is_wild(&self, card: &WildCard) -> bool {
+        match card {
+            WildCard::Natural(card) => self.wild_ranks.contains(&card.rank),
+            WildCard::Joker => true,
+        }
+    }
+}
+
+/// Ranking of a hand with wild cards: the category, then the distinct rank numbers
+/// in the order they break ties (an Ace starting a straight counting as 1).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WildRanking {
+    pub category: RankingCategory,
+    pub tie_breaks: Vec<u8>,
+}
+
+/// The best hand made out of wild cards, along with the cards they stood for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WildHand {
+    pub ranking: WildRanking,
+    pub cards: Vec<Card>,
+}
+
+/// Find the best substitution for the wild cards of a five-card hand.
+///
+/// # Panics
+///
+/// Panics if `cards` does not hold exactly five cards.
+pub fn best_wild_hand(cards: &[WildCard], rules: &WildRules) -> WildHand {
+    assert_eq!(cards.len(), 5, "a hand is made of five cards");
+
+    let (wilds, naturals): (Vec<WildCard>, Vec<WildCard>) = cards.iter()
+        .partition(|card| rules.is_wild(card));
+    let naturals = Vec::from_iter(naturals.into_iter().filter_map(|card| match card {
+        WildCard::Natural(card) => Some(card),
+        WildCard::Joker => None,
+    }));
+
+    // A wild card's suit only matters for flushes, which need the natural cards' suit.
+    let wild_suit = naturals.first().map_or(Suit::Spades, |card| card.suit);
+
+    // Wild cards are interchangeable, so only the multisets of ranks need to be tried.
+    let mut best: Option<WildHand> = None;
+    for wild_ranks in rank_multisets(wilds.len()) {
+        let hand_cards = Vec::from_iter(naturals.iter().copied()
+            .chain(wild_ranks.into_iter().map(|rank| Card::new(rank, wild_suit)))
+        );
+        let ranking = wild_ranking(&hand_cards);
+        let better = match &best {
+            Some(b) => ranking > b.ranking,
+            None => true,
+        };
+        if better {
+            best = Some(WildHand { ranking, cards: hand_cards });
+        }
+    }
+    best.unwrap()
+}
+
+/// All the multisets of `size` ranks, in no particular order.
+fn rank_multisets(size: usize) -> Vec<Vec<Rank>> {
+    fn extend(from: usize, size: usize, current: &mut Vec<Rank>, all: &mut Vec<Vec<Rank>>) {
+        if current.len() == size {
+            all.push(current.clone());
+            return;
+        }
+        for i in from..Rank::ALL.len() {
+            current.push(Rank::ALL[i]);
+            extend(i, size, current, all);
+            current.pop();
+        }
+    }
+    let mut all = vec![];
+    extend(0, size, &mut Vec::with_capacity(size), &mut all);
+    all
+}
+
+/// Rank five cards which may hold duplicates.
+fn wild_ranking(cards: &[Card]) -> WildRanking {
+    let mut counts = [0_u8; 15];
+    for card in cards {
+        counts[card.rank.value() as usize] += 1;
+    }
+    let mut groups = Vec::from_iter((2..=14_u8)
+        .filter(|&value| counts[value as usize] > 0)
+        .map(|value| (counts[value as usize], value))
+    );
+    groups.sort_by(|a, b| b.cmp(a));
+    let tie_breaks = Vec::from_iter(groups.iter().map(|&(_count, value)| value));
+
+    let flush = cards.iter().all(|c| c.suit == cards[0].suit);
+    let straight_high = match tie_breaks.as_slice() {
+        [14, 5, 4, 3, 2] => Some(5),
+        [high, .., low] if groups.len() == 5 && high - low == 4 => Some(*high),
+        _ => None,
+    };
+
+    let category = match (groups[0].0, groups.get(1).map(|g| g.0)) {
+        (5, _) => FiveOfAKind,
+        _ if straight_high.is_some() && flush => StraightFlush,
+        (4, _) => Quads,
+        (3, Some(2)) => Full,
+        _ if flush => Flush,
+        _ if straight_high.is_some() => Straight,
+        (3, _) => Trips,
+        (2, Some(2)) => TwoPair,
+        (2, _) => OnePair,
+        _ => HighestCard,
+    };
+    match straight_high {
+        Some(high) if matches!(category, Straight | StraightFlush) =>
+            WildRanking { category, tie_breaks: vec![high] },
+        _ => WildRanking { category, tie_breaks },
+    }
+}
+
+/// Parse a hand of five cards with jokers, natural cards being distinct.
+pub fn parse_wild_hand(input: &str) -> Result<Vec<WildCard>, PokerParseError> {
+    let mut cards: Vec<WildCard> = Vec::with_capacity(5);
+    for token in input.split_whitespace() {
+        let offset = token.as_ptr() as usize - input.as_ptr() as usize;
+        let card: WildCard = token.parse().map_err(|e: PokerParseError| e.shifted(offset))?;
+        if card != WildCard::Joker && cards.contains(&card) {
+            return Err(PokerParseError::DuplicateCard { token: token.to_string(), offset });
+        }
+        cards.push(card);
+    }
+    match cards.len() {
+        0 => Err(PokerParseError::EmptyInput),
+        5 => Ok(cards),
+        count => Err(PokerParseError::WrongCardCount { count }),
+    }
+}
+
+/// Same as `try_winning_hands`, wild cards being substituted following `rules`.
+pub fn winning_wild_hands<'a>(hands: &[&'a str], rules: &WildRules) -> Result<Vec<&'a str>, InvalidHand<'a>> {
+    let mut ranked_hands: Vec<(&'a str, WildRanking)> = Vec::with_capacity(hands.len());
+    for (index, &hand) in hands.iter().enumerate() {
+        let cards = parse_wild_hand(hand)
+            .map_err(|error| InvalidHand { index, hand, error })?;
+        ranked_hands.push((hand, best_wild_hand(&cards, rules).ranking));
+    }
+
+    let mut best_hands: Vec<&'a str> = vec![];
+    let mut top_ranking: Option<&WildRanking> = None;
+    for (hand, ranking) in ranked_hands.iter() {
+        match top_ranking.map(|top| ranking.cmp(top)) {
+            None | Some(Ordering::Greater) => {
+                top_ranking = Some(ranking);
+                best_hands = vec![*hand];
+            }
+            Some(Ordering::Equal) => best_hands.push(hand),
+            Some(Ordering::Less) => {}
+        }
+    }
+    Ok(best_hands)
+}
@@ -0,0 +1,54 @@
+use poker::arena::{AlwaysCall, Arena, GameView, HandStrengthThreshold, Player};
+use poker::game::{Action, Blinds};
+
+const BLINDS: Blinds = Blinds { small: 1, big: 2 };
+
+struct AlwaysRaiseOne;
+
+impl Player for AlwaysRaiseOne {
+    fn act(&mut self, _view: &GameView) -> Action {
+        Action::Raise(1)
+    }
+}
+
+#[test]
+fn test_runs_are_reproducible_and_zero_sum() {
+    let arena = || Arena::new(
+        vec![Box::new(AlwaysCall), Box::new(HandStrengthThreshold::default()), Box::new(AlwaysCall)],
+        200, BLINDS, 42,
+    );
+    let stats = arena().run(300).unwrap();
+    assert_eq!(stats, arena().run(300).unwrap());
+    assert_eq!(stats.hands, 300);
+    assert_eq!(stats.players.iter().map(|p| p.net_chips).sum::<i64>(), 0);
+    assert!(stats.players.iter().all(|p| p.hands_won <= 300));
+}
+
+#[test]
+fn test_threshold_bot_beats_calling_station() {
+    let mut arena = Arena::new(
+        vec![Box::new(HandStrengthThreshold::default()), Box::new(AlwaysCall)],
+        200, BLINDS, 7,
+    );
+    let stats = arena.run(2_000).unwrap();
+    assert!(stats.bb_per_100(0) > 0.0, "{:?}", stats);
+    assert_eq!(stats.bb_per_100(0), -stats.bb_per_100(1));
+}
+
+#[test]
+fn test_illegal_actions_fold() {
+    let mut arena = Arena::new(vec![Box::new(AlwaysRaiseOne), Box::new(AlwaysCall)], 200, BLINDS, 1);
+    let stats = arena.run(100).unwrap();
+    // Folding every hand loses the blinds: 1.5 big blinds every two hands.
+    assert_eq!(stats.bb_per_100(0), -75.0);
+    assert_eq!(stats.win_rate(0), 0.0);
+    assert_eq!(stats.win_rate(1), 1.0);
+}
+
+#[test]
+fn test_no_hands_give_zero_rates() {
+    let stats = Arena::new(vec![Box::new(AlwaysCall), Box::new(AlwaysCall)], 200, BLINDS, 3).run(0).unwrap();
+    assert_eq!(stats.hands, 0);
+    assert_eq!(stats.win_rate(0), 0.0);
+    assert_eq!(stats.bb_per_100(1), 0.0);
+}
@@ -0,0 +1,22 @@
+//! Helpers shared by the integration tests, each test crate using a few of them.
+#![allow(dead_code)]
+
+use poker::deck::Deck;
+use poker::Card;
+
+pub fn cards(input: &str) -> Vec<Card> {
+    input.split_whitespace().map(Card::from_string).collect()
+}
+
+pub fn hole_cards(input: &str) -> [Card; 2] {
+    cards(input).try_into().unwrap()
+}
+
+pub fn four_cards(input: &str) -> [Card; 4] {
+    cards(input).try_into().unwrap()
+}
+
+/// A deck dealing `input` in order.
+pub fn stacked_deck(input: &str) -> Deck {
+    Deck::from_cards(cards(input).into_iter().rev().collect()).unwrap()
+}
@@ -0,0 +1,54 @@
+use poker::compare::{explain, Explanation, Winner};
+use poker::variant::Variant;
+use poker::{Hand, Rank, RankingCategory};
+
+fn explain_str(first: &str, second: &str) -> Explanation {
+    explain(&Hand::from_string(first), &Hand::from_string(second))
+}
+
+#[test]
+fn test_category_decides() {
+    let explanation = explain_str("4S 5S 7S 9S JS", "KS KH 7D 7C KD");
+    assert_eq!(explanation, Explanation::Category {
+        winner: Winner::Second,
+        winning: RankingCategory::Full,
+        losing: RankingCategory::Flush,
+    });
+    assert_eq!(explanation.to_string(), "Full house beats Flush");
+}
+
+#[test]
+fn test_kicker_decides() {
+    let explanation = explain_str("AS 4H AD 4C QS", "AH 4S AC 4D 10S");
+    assert_eq!(explanation, Explanation::TieBreak {
+        winner: Winner::First,
+        category: RankingCategory::TwoPair,
+        position: 2,
+        winning: Rank::Queen,
+        losing: Rank::Ten,
+    });
+}
+
+#[test]
+fn test_wheel_loses_to_six_high_straight() {
+    let explanation = explain_str("AS 2H 3D 4C 5S", "2S 3H 4D 5C 6S");
+    assert_eq!(explanation.winner(), Some(Winner::Second));
+    assert_eq!(explanation.to_string(), "both have Straight, 6 beats 5");
+}
+
+#[test]
+fn test_split() {
+    let explanation = explain_str("4S 5S 7H 9S JS", "4H 5H 7S 9H JH");
+    assert_eq!(explanation, Explanation::Split { category: RankingCategory::HighestCard });
+    assert_eq!(explanation.winner(), None);
+}
+
+#[test]
+fn test_short_deck_category_order() {
+    let short_deck = Variant::short_deck();
+    let flush = Hand::parse_in("6H 8H 9H JH KH", &short_deck).unwrap();
+    let full = Hand::parse_in("QS QH QD 7C 7D", &short_deck).unwrap();
+    let explanation = explain(&flush, &full);
+    assert_eq!(explanation.winner(), Some(Winner::First));
+    assert_eq!(explanation.to_string(), "Flush beats Full house");
+}
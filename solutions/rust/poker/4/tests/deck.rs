@@ -0,0 +1,46 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use poker::deck::{Deck, DeckError};
+use poker::Card;
+
+#[test]
+fn test_standard_deck() {
+    let deck = Deck::new();
+    assert_eq!(deck.len(), 52);
+    assert!(deck.contains(&Card::from_string("10H")));
+}
+
+#[test]
+fn test_shuffle_is_reproducible() {
+    let mut first = Deck::new();
+    let mut second = Deck::new();
+    first.shuffle(&mut StdRng::seed_from_u64(1));
+    second.shuffle(&mut StdRng::seed_from_u64(1));
+    assert_eq!(first.cards(), second.cards());
+    assert_ne!(first.cards(), Deck::new().cards());
+}
+
+#[test]
+fn test_deal_to_players_and_burn() {
+    let mut deck = Deck::new();
+    let top_cards: Vec<Card> = deck.cards().iter().rev().take(5).copied().collect();
+
+    let hands = deck.deal_to(2, 2).unwrap();
+    assert_eq!(hands[0], vec![top_cards[0], top_cards[2]]);
+    assert_eq!(hands[1], vec![top_cards[1], top_cards[3]]);
+    assert_eq!(deck.burn(), Ok(top_cards[4]));
+    assert_eq!(deck.len(), 47);
+}
+
+#[test]
+fn test_refuses_duplicates_and_overdealing() {
+    let mut deck = Deck::new();
+    let ace = deck.remove(&Card::from_string("AS")).unwrap();
+    assert_eq!(deck.remove(&ace), Err(DeckError::CardNotInDeck(ace)));
+    assert_eq!(deck.return_card(Card::from_string("KS")), Err(DeckError::DuplicateCard(Card::from_string("KS"))));
+    assert_eq!(deck.deal_to(6, 9), Err(DeckError::NotEnoughCards { needed: 54, left: 51 }));
+
+    let duplicated = vec![Card::from_string("2C"), Card::from_string("2C")];
+    assert!(Deck::from_cards(duplicated).is_err());
+}
@@ -0,0 +1,27 @@
+use poker::describe::Language;
+use poker::Hand;
+
+fn describe(input: &str) -> String {
+    Hand::from_string(input).describe()
+}
+
+#[test]
+fn test_describe_every_category() {
+    assert_eq!(describe("4S 5H 7D 9C AS"), "High card, Ace");
+    assert_eq!(describe("JS JH 7D AC 4S"), "One pair, Jacks, Ace kicker");
+    assert_eq!(describe("AS 4H AD 4C QS"), "Two pair, Aces and Fours, Queen kicker");
+    assert_eq!(describe("6S 6H 6D 7C JS"), "Three of a kind, Sixes");
+    assert_eq!(describe("AS 2H 3D 4C 5S"), "Straight, Five high (wheel)");
+    assert_eq!(describe("9S 10H 8D 7C 6S"), "Straight, Ten high");
+    assert_eq!(describe("4S 5S 7S 9S JS"), "Flush, Jack high");
+    assert_eq!(describe("KS KH 7D 7C KD"), "Full house, Kings over Sevens");
+    assert_eq!(describe("QS QH QD QC JS"), "Four of a kind, Queens");
+    assert_eq!(describe("9H JH 10H 8H 7H"), "Straight flush, Jack high");
+    assert_eq!(describe("10H JH QH KH AH"), "Royal flush");
+}
+
+#[test]
+fn test_describe_in_french() {
+    let hand = Hand::from_string("KS KH 7D 7C KD");
+    assert_eq!(hand.describe_in(Language::French), "Full, Rois par les Sept");
+}
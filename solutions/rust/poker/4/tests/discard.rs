@@ -0,0 +1,69 @@
+use poker::discard::advise_discard;
+use poker::equity::EquityMethod;
+use poker::variant::Variant;
+use poker::{Card, Hand, RankingCategory};
+
+mod common;
+
+use common::cards;
+
+fn sorted(mut cards: Vec<Card>) -> Vec<Card> {
+    cards.sort();
+    cards
+}
+
+/// A video poker style paytable, any pair paying back the bet.
+fn paytable(category: RankingCategory) -> f64 {
+    match category {
+        RankingCategory::HighestCard => 0.0,
+        RankingCategory::OnePair => 1.0,
+        RankingCategory::TwoPair => 2.0,
+        RankingCategory::Trips => 3.0,
+        RankingCategory::Straight => 4.0,
+        RankingCategory::Flush => 6.0,
+        RankingCategory::Full => 9.0,
+        RankingCategory::Quads => 25.0,
+        RankingCategory::StraightFlush => 50.0,
+        RankingCategory::FiveOfAKind => 100.0,
+    }
+}
+
+#[test]
+fn test_pat_hand_is_kept() {
+    let hand = Hand::from_string("9H 10H JH QH KH");
+    let options = advise_discard(&hand, paytable, EquityMethod::Exhaustive).unwrap();
+    assert_eq!(options.len(), 32);
+    assert!(options[0].discard.is_empty());
+    assert_eq!(options[0].chance_of(RankingCategory::StraightFlush), 1.0);
+    assert_eq!(options[0].expected_payout, 50.0);
+}
+
+#[test]
+fn test_keep_the_pair_exactly() {
+    let hand = Hand::from_string("AS AD 7C 4H 2S");
+    let options = advise_discard(&hand, paytable, EquityMethod::Exhaustive).unwrap();
+    assert_eq!(sorted(options[0].hold.clone()), sorted(cards("AS AD")));
+    assert_eq!(options[0].discard.len(), 3);
+    assert!(options.windows(2).all(|pair| pair[0].expected_payout >= pair[1].expected_payout));
+    assert!(options.iter().all(|option| (option.category_odds.iter().sum::<f64>() - 1.0).abs() < 1e-9));
+    // Holding a pair, it can only get better.
+    assert_eq!(options[0].chance_of(RankingCategory::HighestCard), 0.0);
+}
+
+#[test]
+fn test_keep_four_to_a_flush_sampled() {
+    let hand = Hand::from_string("2H 6H 9H KH 4C");
+    let method = EquityMethod::MonteCarlo { iterations: 2_000, seed: 7 };
+    let options = advise_discard(&hand, paytable, method).unwrap();
+    assert_eq!(options[0].discard, cards("4C"));
+}
+
+#[test]
+fn test_draw_from_the_hands_own_decks() {
+    // The replacements come from two decks, the pair of Aces being no error.
+    let hand = Hand::parse_in("AS AS KD QC JH", &Variant::standard().with_decks(2)).unwrap();
+    let method = EquityMethod::MonteCarlo { iterations: 200, seed: 1 };
+    let options = advise_discard(&hand, paytable, method).unwrap();
+    assert_eq!(options[0].hold.iter().filter(|&&card| card == Card::from_string("AS")).count(), 2);
+    assert_eq!(options[0].chance_of(RankingCategory::HighestCard), 0.0);
+}
@@ -0,0 +1,63 @@
+use poker::equity::{equity, EquityError, EquityMethod};
+use poker::Card;
+
+mod common;
+
+use common::{cards, hole_cards};
+
+#[test]
+fn test_exhaustive_equity_on_the_turn() {
+    // Only the two remaining nines save the second player.
+    let players = [hole_cards("AS AD"), hole_cards("9C 9D")];
+    let board = cards("2H 7D KS 3C");
+    let equities = equity(&players, &board, EquityMethod::Exhaustive).unwrap();
+
+    let second_wins = 2.0 / 44.0;
+    assert!((equities[1].win - second_wins).abs() < 1e-9);
+    assert!((equities[0].win - (1.0 - second_wins)).abs() < 1e-9);
+    assert_eq!(equities[0].tie, 0.0);
+}
+
+#[test]
+fn test_exhaustive_equity_on_the_flop() {
+    // Of the 990 turn and river pairs, the 87 holding a nine save the second player,
+    // except the 4 which also bring an Ace.
+    let players = [hole_cards("AS AD"), hole_cards("9C 9D")];
+    let board = cards("2H 7D KS");
+    let equities = equity(&players, &board, EquityMethod::Exhaustive).unwrap();
+
+    assert!((equities[1].win - 83.0 / 990.0).abs() < 1e-9);
+    assert!((equities[0].win - 907.0 / 990.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_monte_carlo_is_reproducible() {
+    let players = [hole_cards("AS KS"), hole_cards("QH QD")];
+    let method = EquityMethod::MonteCarlo { iterations: 500, seed: 42 };
+    let first = equity(&players, &[], method).unwrap();
+    let second = equity(&players, &[], method).unwrap();
+
+    assert_eq!(first, second);
+    let total: f64 = first.iter().map(|e| e.equity).sum();
+    assert!((total - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_duplicate_card_is_rejected() {
+    let players = [hole_cards("AS KS"), hole_cards("AS QD")];
+    assert_eq!(
+        equity(&players, &[], EquityMethod::Exhaustive),
+        Err(EquityError::DuplicateCard(Card::from_string("AS")))
+    );
+}
+
+#[test]
+fn test_too_many_players_for_the_board() {
+    let deck = poker::deck::Deck::new();
+    let players = Vec::from_iter(deck.cards().chunks(2).map(|pair| [pair[0], pair[1]]));
+    for method in [EquityMethod::Exhaustive, EquityMethod::MonteCarlo { iterations: 10, seed: 1 }] {
+        assert_eq!(equity(&players[..24], &[], method), Err(EquityError::TooManyPlayers(24)));
+        assert_eq!(equity(&players[..25], &[], method), Err(EquityError::TooManyPlayers(25)));
+    }
+    assert!(equity(&players[..23], &[], EquityMethod::MonteCarlo { iterations: 10, seed: 1 }).is_ok());
+}
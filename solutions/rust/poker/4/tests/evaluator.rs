@@ -0,0 +1,68 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use poker::deck::Deck;
+use poker::evaluator::{category_of, evaluate, CardSet, PackedCard};
+use poker::{Card, Hand, Rank, PokerParseError, RankingCategory};
+
+fn score(input: &str) -> u32 {
+    evaluate(input.parse().unwrap())
+}
+
+#[test]
+fn test_categories() {
+    let expectations = [
+        ("4S 5H 7D 9C JS", RankingCategory::HighestCard),
+        ("4S 4H 7D 9C JS", RankingCategory::OnePair),
+        ("4S 4H 7D 7C JS", RankingCategory::TwoPair),
+        ("4S 4H 4D 7C JS", RankingCategory::Trips),
+        ("AS 2H 3D 4C 5S", RankingCategory::Straight),
+        ("4S 5S 7S 9S JS", RankingCategory::Flush),
+        ("4S 4H 4D 7C 7S", RankingCategory::Full),
+        ("4S 4H 4D 4C JS", RankingCategory::Quads),
+        ("10H JH QH KH AH", RankingCategory::StraightFlush),
+    ];
+    for (input, category) in expectations {
+        assert_eq!(category_of(score(input)) as u8, category as u8, "{}", input);
+    }
+}
+
+#[test]
+fn test_best_five_of_seven() {
+    assert_eq!(score("AH KH 2C QH 7D JH 10H"), score("AH KH QH JH 10H"));
+    assert_eq!(score("4S 4H 4D 7C 7S 7H 2C"), score("7C 7S 7H 4S 4H"));
+    assert!(score("AS 2H 3D 4C 5S 6S KD") > score("AS 2H 3D 4C 5S"));
+}
+
+#[test]
+fn test_same_ordering_as_ranking() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut deck = Deck::new().cards().to_vec();
+    for _ in 0..2000 {
+        deck.shuffle(&mut rng);
+        let first = Hand::try_from(&deck[..5]).unwrap();
+        let second = Hand::try_from(&deck[5..10]).unwrap();
+
+        let fast_order = evaluate(first.card_set()).cmp(&evaluate(second.card_set()));
+        assert_eq!(fast_order, first.ranking().cmp(&second.ranking()), "{} vs {}", first, second);
+    }
+}
+
+#[test]
+fn test_parse_packed_cards() {
+    assert_eq!("10H".parse::<PackedCard>().unwrap().rank(), Rank::Ten);
+    assert_eq!(
+        "4S 5S 7H 4S 8D".parse::<CardSet>(),
+        Err(PokerParseError::DuplicateCard { token: "4S".to_string(), offset: 9 })
+    );
+}
+
+#[test]
+fn test_repeated_card_in_a_slice() {
+    let cards = Vec::from_iter(["2S", "4S", "7H", "4S", "KD"].map(Card::from_string));
+    assert_eq!(
+        Hand::try_from(cards.as_slice()).err(),
+        Some(PokerParseError::RepeatedCard { card: Card::from_string("4S"), index: 3 })
+    );
+}
@@ -0,0 +1,93 @@
+use poker::deck::Deck;
+use poker::game::{Action, Blinds, Game, GameError, GameEvent, Street};
+
+mod common;
+
+use common::{hole_cards, stacked_deck};
+
+const BLINDS: Blinds = Blinds { small: 5, big: 10 };
+
+#[test]
+fn test_blinds_and_first_to_act() {
+    let game = Game::new(&[1000, 1000, 1000], 0, BLINDS, Deck::new()).unwrap();
+    assert_eq!(game.street_bet(1), 5);
+    assert_eq!(game.street_bet(2), 10);
+    assert_eq!(game.to_act(), Some(0));
+
+    let heads_up = Game::new(&[1000, 1000], 0, BLINDS, Deck::new()).unwrap();
+    assert_eq!(heads_up.street_bet(0), 5);
+    assert_eq!(heads_up.to_act(), Some(0));
+}
+
+#[test]
+fn test_fold_around_to_the_big_blind() {
+    let mut game = Game::new(&[1000, 1000, 1000], 0, BLINDS, Deck::new()).unwrap();
+    game.act(Action::Fold).unwrap();
+    game.act(Action::Fold).unwrap();
+
+    assert!(game.is_finished());
+    assert_eq!(game.stacks(), vec![1000, 995, 1005]);
+    assert_eq!(game.act(Action::Check), Err(GameError::HandOver));
+}
+
+#[test]
+fn test_min_raise_rules() {
+    let mut game = Game::new(&[1000, 1000, 1000], 0, BLINDS, Deck::new()).unwrap();
+    assert_eq!(game.legal_actions().unwrap().min_raise_to, Some(20));
+    assert_eq!(game.act(Action::Raise(15)), Err(GameError::RaiseTooSmall { min: 20 }));
+    assert_eq!(game.act(Action::Bet(30)), Err(GameError::BetNotAllowed));
+    assert_eq!(game.act(Action::Check), Err(GameError::CannotCheck));
+
+    game.act(Action::Raise(35)).unwrap();
+    assert_eq!(game.legal_actions().unwrap().min_raise_to, Some(60));
+    assert_eq!(game.act(Action::Raise(2000)), Err(GameError::NotEnoughChips { max: 1000 }));
+}
+
+#[test]
+fn test_short_all_in_does_not_reopen_betting() {
+    let mut game = Game::new(&[1000, 1000, 45], 0, BLINDS, Deck::new()).unwrap();
+    // Seat 0 opens to 30, seat 1 calls, seat 2 (big blind) shoves 45: a short raise.
+    assert_eq!(game.to_act(), Some(0));
+    game.act(Action::Raise(30)).unwrap();
+    game.act(Action::Call).unwrap();
+    game.act(Action::AllIn).unwrap();
+
+    assert_eq!(game.to_act(), Some(0));
+    assert_eq!(game.legal_actions().unwrap().min_raise_to, None);
+    assert_eq!(game.act(Action::Raise(100)), Err(GameError::RaiseNotAllowed));
+    game.act(Action::Call).unwrap();
+    game.act(Action::Call).unwrap();
+    assert_eq!(game.street(), Street::Flop);
+}
+
+#[test]
+fn test_all_in_runs_out_the_board_to_showdown() {
+    // Hole cards are dealt from the seat left of the button, then burn and board cards.
+    let deck = stacked_deck("AS KD AH KC 2C 7D 8S 9H 3C 4D 5C JS 6H QH");
+    let mut game = Game::new(&[500, 500], 1, BLINDS, deck).unwrap();
+    assert_eq!(game.hole_cards(0), hole_cards("AS AH"));
+
+    game.act(Action::AllIn).unwrap();
+    let events = game.act(Action::Call).unwrap().to_vec();
+
+    assert!(game.is_finished());
+    assert_eq!(game.board().len(), 5);
+    assert_eq!(game.stacks(), vec![1000, 0]);
+    assert!(events.contains(&GameEvent::PotWon { seat: 0, amount: 1000 }));
+}
+
+#[test]
+fn test_checked_down_hand_goes_through_every_street() {
+    let mut game = Game::new(&[1000, 1000], 0, BLINDS, Deck::new()).unwrap();
+    game.act(Action::Call).unwrap();
+    game.act(Action::Check).unwrap();
+    for street in [Street::Flop, Street::Turn, Street::River] {
+        assert_eq!(game.street(), street);
+        // The big blind acts first after the flop when heads-up.
+        assert_eq!(game.to_act(), Some(1));
+        game.act(Action::Check).unwrap();
+        game.act(Action::Check).unwrap();
+    }
+    assert!(game.is_finished());
+    assert_eq!(game.stacks().iter().sum::<u64>(), 2000);
+}
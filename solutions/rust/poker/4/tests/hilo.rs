@@ -0,0 +1,46 @@
+use poker::hilo::{hilo_showdown, omaha_hilo_showdown, qualifies_eight_or_better};
+use poker::Hand;
+
+mod common;
+
+use common::{cards, four_cards};
+
+#[test]
+fn test_eight_or_better_qualifier() {
+    assert!(qualifies_eight_or_better(&Hand::from_string("AS 2H 3D 4C 8S")));
+    assert!(!qualifies_eight_or_better(&Hand::from_string("AS 2H 3D 4C 9S")));
+    assert!(!qualifies_eight_or_better(&Hand::from_string("AS 2H 3D 3C 8S")));
+}
+
+#[test]
+fn test_split_between_high_and_low() {
+    let hands = ["KS KH 9D 9C 2S", "AS 2H 3D 4C 7S", "AH 2D 3S 5C 8D"];
+    let showdown = hilo_showdown(&hands).unwrap();
+    assert_eq!(showdown.high, vec![hands[0]]);
+    assert_eq!(showdown.low, Some(vec![hands[1]]));
+    assert!(showdown.scoops.is_empty());
+}
+
+#[test]
+fn test_no_qualifying_low() {
+    let hands = ["KS KH 9D 9C 2S", "AS 2H 3D 4C 9S"];
+    let showdown = hilo_showdown(&hands).unwrap();
+    assert_eq!(showdown.low, None);
+    assert_eq!(showdown.scoops, vec![hands[0]]);
+}
+
+#[test]
+fn test_wheel_scoops() {
+    let hands = ["AS 2H 3D 4C 5S", "KH QH 9H 7C 6D"];
+    let showdown = hilo_showdown(&hands).unwrap();
+    assert_eq!(showdown.scoops, vec![hands[0]]);
+}
+
+#[test]
+fn test_omaha_hilo() {
+    let board = cards("2S 5H 7D KC KD");
+    let players = [four_cards("KS QH JD 10C"), four_cards("AS 3C QD QS"), four_cards("AH 4C 9S 9C")];
+    let showdown = omaha_hilo_showdown(&board, &players);
+    assert_eq!(showdown.high, vec![0]);
+    assert_eq!(showdown.low, Some(vec![1]));
+}
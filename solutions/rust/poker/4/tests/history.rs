@@ -0,0 +1,184 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use poker::deck::Deck;
+use poker::game::{Action, Blinds, Game, Street};
+use poker::history::{parse_histories, parse_history, write_history, ActionKind, HistoryError};
+use poker::Card;
+
+const SAMPLE: &str = "\
+PokerStars Hand #2001: Hold'em No Limit (5/10) - 2024/03/02 21:14:05 ET
+Table 'Alpha' 6-max Seat #1 is the button
+Seat 1: Alice (1000 in chips)
+Seat 2: Bob Smith (400 in chips)
+Seat 3: Carol (1000 in chips)
+Bob Smith: posts small blind 5
+Carol: posts big blind 10
+*** HOLE CARDS ***
+Dealt to Alice [As Ah]
+Alice: raises 20 to 30
+Bob Smith: raises 370 to 400 and is all-in
+Carol: folds
+Alice: calls 370
+*** FLOP *** [2c 7d 8s]
+*** TURN *** [2c 7d 8s] [Td]
+*** RIVER *** [2c 7d 8s Td] [Kh]
+*** SHOW DOWN ***
+Alice: shows [As Ah] (a pair of Aces)
+Bob Smith: shows [Kd Kc] (three of a kind, Kings)
+Bob Smith collected 810 from pot
+*** SUMMARY ***
+Total pot 810 | Rake 0
+Board [2c 7d 8s Td Kh]
+Seat 2: Bob Smith (small blind) showed [Kd Kc] and won (810)
+";
+
+const CASH_GAME: &str = "\
+PokerStars Hand #208181711541:  Hold'em No Limit ($0.01/$0.02 USD) - 2020/01/19 16:40:12 ET
+Table 'Aaltje II' 6-max Seat #1 is the button
+Seat 1: Alice ($2.00 in chips)
+Seat 2: Bob ($1.87 in chips)
+Seat 4: Carol ($2.41 in chips) is sitting out
+Alice: posts small blind $0.01
+Bob: posts big blind $0.02
+*** HOLE CARDS ***
+Dealt to Alice [Ah Kd]
+Alice: raises $0.04 to $0.06
+Bob: calls $0.04
+*** FLOP *** [Ks 7h 2c]
+Bob: checks
+Alice: bets $0.08
+Bob: calls $0.08
+*** TURN *** [Ks 7h 2c] [9d]
+Bob: checks
+Alice: checks
+*** RIVER *** [Ks 7h 2c 9d] [3s]
+Bob: bets $0.10
+Alice: calls $0.10
+*** SHOW DOWN ***
+Bob: shows [Qc Qd] (a pair of Queens)
+Alice: shows [Ah Kd] (a pair of Kings)
+Alice collected $0.47 from pot
+*** SUMMARY ***
+Total pot $0.48 | Rake $0.01
+Board [Ks 7h 2c 9d 3s]
+Seat 1: Alice (button) (small blind) showed [Ah Kd] and won ($0.47) with a pair of Kings
+Seat 2: Bob (big blind) showed [Qc Qd] and lost with a pair of Queens
+";
+
+#[test]
+fn test_parse_pokerstars_history() {
+    let history = parse_history(SAMPLE).unwrap();
+    assert_eq!(history.hand_id, "2001");
+    assert_eq!(history.table, "Alpha");
+    assert_eq!(history.button_seat, 1);
+    assert_eq!(history.seats[1].name, "Bob Smith");
+    assert_eq!(history.seats[1].chips, 400);
+    assert_eq!(history.blinds, Some(Blinds { small: 5, big: 10 }));
+    assert_eq!(history.board.len(), 5);
+    assert_eq!(history.board[3], Card::from_string("10D"));
+    assert_eq!(history.actions[3].kind, ActionKind::Raise { by: 370, to: 400 });
+    assert!(history.actions[3].all_in);
+    assert_eq!(history.actions.last().unwrap().street, Street::Preflop);
+    assert_eq!(history.collected, vec![("Bob Smith".to_string(), 810)]);
+    assert_eq!(history.verify_winners(), Ok(()));
+}
+
+#[test]
+fn test_wrong_winner_is_reported() {
+    let tampered = SAMPLE.replace("Bob Smith collected 810", "Alice collected 810");
+    let history = parse_history(&tampered).unwrap();
+    assert_eq!(history.verify_winners(), Err(HistoryError::WrongWinners {
+        expected: vec!["Bob Smith".to_string()],
+        reported: vec!["Alice".to_string()],
+    }));
+}
+
+#[test]
+fn test_bad_card_reports_its_line() {
+    let broken = SAMPLE.replace("[Kh]", "[Kx]");
+    assert!(matches!(parse_history(&broken), Err(HistoryError::BadCard { line: 16, .. })));
+}
+
+#[test]
+fn test_written_game_parses_back() {
+    let mut deck = Deck::new();
+    deck.shuffle(&mut StdRng::seed_from_u64(3));
+    let mut game = Game::new(&[1000, 600, 1000], 0, Blinds { small: 5, big: 10 }, deck).unwrap();
+    game.act(Action::Raise(40)).unwrap();
+    game.act(Action::Call).unwrap();
+    game.act(Action::Fold).unwrap();
+    game.act(Action::Bet(100)).unwrap();
+    game.act(Action::AllIn).unwrap();
+    game.act(Action::Call).unwrap();
+    assert!(game.is_finished());
+
+    let text = write_history(&game, &["Ann", "Ben", "Cid"], 7, "Sim");
+    let history = parse_history(&text).unwrap();
+    assert_eq!(history.seats.len(), 3);
+    assert_eq!(history.dealt.len(), 3);
+    assert_eq!(history.board, game.board());
+    assert_eq!(history.verify_winners(), Ok(()));
+
+    let collected: u64 = history.collected.iter().map(|(_n, amount)| amount).sum();
+    assert_eq!(collected, 1210);
+
+    let both = parse_histories(&format!("{}\n\n{}", text, SAMPLE)).unwrap();
+    assert_eq!(both.len(), 2);
+}
+
+/// Everyone folds to Bob's big blind, Carol's seat line being given.
+fn folded_to_the_big_blind(carol: &str) -> String {
+    format!("\
+PokerStars Hand #208181711542:  Hold'em No Limit (1/2) - 2020/01/19 16:42:03 ET
+Table 'Aaltje II' 6-max Seat #4 is the button
+Seat 1: Alice (200 in chips)
+Seat 2: Bob (187 in chips)
+{}
+Alice: posts small blind 1
+Bob: posts big blind 2
+*** HOLE CARDS ***
+Dealt to Alice [Ah 7d]
+{}Alice: folds
+Uncalled bet (1) returned to Bob
+Bob collected 2 from pot
+*** SUMMARY ***
+Total pot 2 | Rake 0
+", carol, if carol.contains("sitting out") { "" } else { "Carol: folds\n" })
+}
+
+#[test]
+fn test_everyone_folds() {
+    let history = parse_history(&folded_to_the_big_blind("Seat 4: Carol (241 in chips)")).unwrap();
+    assert_eq!(history.collected, vec![("Bob".to_string(), 2)]);
+    assert_eq!(history.verify_winners(), Ok(()));
+}
+
+#[test]
+fn test_sitting_out_seat_is_not_in_the_hand() {
+    let history = parse_history(&folded_to_the_big_blind("Seat 4: Carol (241 in chips) is sitting out")).unwrap();
+    assert_eq!(history.seats.len(), 3);
+    assert_eq!(history.verify_winners(), Ok(()));
+}
+
+#[test]
+fn test_parse_cash_game_amounts_in_cents() {
+    let history = parse_history(CASH_GAME).unwrap();
+    assert_eq!(history.blinds, Some(Blinds { small: 1, big: 2 }));
+    assert_eq!(history.seats.len(), 3);
+    assert_eq!(history.seats[0].chips, 200);
+    assert_eq!(history.seats[1].chips, 187);
+    assert_eq!(history.seats[2].name, "Carol");
+    assert_eq!(history.actions[2].kind, ActionKind::Raise { by: 4, to: 6 });
+    assert_eq!(history.collected, vec![("Alice".to_string(), 47)]);
+    assert_eq!(history.verify_winners(), Ok(()));
+}
+
+#[test]
+fn test_bad_seat_line_is_reported() {
+    let broken = CASH_GAME.replace("Bob ($1.87 in chips)", "Bob ($1.8.7 in chips)");
+    assert!(matches!(parse_history(&broken), Err(HistoryError::Malformed { line: 4, .. })));
+
+    let broken = CASH_GAME.replace("Bob ($1.87 in chips)", "Bob $1.87");
+    assert!(matches!(parse_history(&broken), Err(HistoryError::Malformed { line: 4, .. })));
+}
@@ -0,0 +1,46 @@
+use poker::holdem::{best_hand_from, holdem_winners};
+
+mod common;
+
+use common::{cards, hole_cards};
+
+#[test]
+fn test_best_hand_from_seven_cards() {
+    let best = best_hand_from(&cards("AH KH 2C QH 7D JH 10H")).unwrap();
+    assert_eq!(best.cards, cards("AH KH QH JH 10H"));
+}
+
+#[test]
+fn test_best_hand_needs_five_cards() {
+    assert!(best_hand_from(&cards("AH KH 2C QH")).is_none());
+}
+
+#[test]
+fn test_holdem_winners() {
+    let board = cards("2C 7D 9H KS 3S");
+    let players = [
+        hole_cards("KH 4D"),
+        hole_cards("9C 9D"),
+        hole_cards("AS QS"),
+    ];
+    let winners = holdem_winners(&board, &players);
+    assert_eq!(winners.len(), 1);
+    assert_eq!(winners[0].0, 1);
+}
+
+#[test]
+fn test_holdem_split_pot_on_board() {
+    let board = cards("10S JS QS KS AS");
+    let players = [
+        hole_cards("2H 3D"),
+        hole_cards("4C 5D"),
+    ];
+    let winners: Vec<usize> = holdem_winners(&board, &players).into_iter().map(|(i, _b)| i).collect();
+    assert_eq!(winners, vec![0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "at least the flop")]
+fn test_holdem_winners_need_a_flop() {
+    holdem_winners(&cards("2C 7D"), &[hole_cards("AS AH"), hole_cards("KS KH")]);
+}
@@ -0,0 +1,26 @@
+use poker::omaha::{best_omaha_hand, omaha_winners};
+
+mod common;
+
+use common::{cards, four_cards};
+
+#[test]
+fn test_must_use_exactly_two_hole_cards() {
+    // Four hearts on the board but a single heart in hand is no flush in Omaha.
+    let hole_cards = cards("AH KC QC 2D");
+    let best = best_omaha_hand(&hole_cards, &cards("3H 7H 9H JH 4S")).unwrap();
+    assert_eq!(best.cards.iter().filter(|c| hole_cards.contains(c)).count(), 2);
+
+    let flush = best_omaha_hand(&cards("AH KH QC 2D"), &cards("3H 7H 9H JH 4S")).unwrap();
+    assert!(best.ranking < flush.ranking);
+}
+
+#[test]
+fn test_omaha_winners_reports_combination() {
+    let board = cards("3H 7H 9H JC 4S");
+    let players = [four_cards("AH KH QC 2D"), four_cards("9C 9D 8S 8D")];
+    let winners = omaha_winners(&board, &players);
+    assert_eq!(winners.len(), 1);
+    assert_eq!(winners[0].0, 0);
+    assert_eq!(winners[0].1.cards, cards("AH KH 3H 7H 9H"));
+}
@@ -0,0 +1,71 @@
+use poker::outs::{analyze_outs, draws, Draw, OutsError};
+use poker::{Card, Rank, RankingCategory, Suit};
+
+mod common;
+
+use common::{cards, hole_cards};
+
+#[test]
+fn test_flush_draw_on_the_flop() {
+    let analysis = analyze_outs(&hole_cards("AH KH"), &cards("2H 7H QC")).unwrap();
+    assert_eq!(analysis.current, RankingCategory::HighestCard);
+    assert_eq!(analysis.draws, vec![Draw::Flush(Suit::Hearts)]);
+
+    let flush_outs = analysis.outs.iter()
+        .filter(|out| out.category == RankingCategory::Flush)
+        .count();
+    assert_eq!(flush_outs, 9);
+    // Any other Ace or King pairs up. A Two, Seven or Queen only pairs the board, the Queen
+    // of hearts counting as a flush out.
+    assert_eq!(analysis.outs.len(), 9 + 2 * 3);
+    assert!(analysis.outs.iter().all(|out| out.category == RankingCategory::Flush
+        || [Rank::Ace, Rank::King].contains(&out.card.rank)));
+
+    let (_category, flush_odds) = analysis.odds_by_category.iter()
+        .find(|(category, _odds)| *category == RankingCategory::Flush)
+        .unwrap();
+    assert!((flush_odds - (1.0 - 703.0 / 1081.0)).abs() < 1e-9);
+    assert!(analysis.improve_by_river > *flush_odds);
+}
+
+#[test]
+fn test_straight_draws() {
+    assert_eq!(draws(&cards("8S 9D 10C JH 2S")), vec![Draw::OpenEndedStraight]);
+    assert_eq!(draws(&cards("8S 9D JH QC 2S")), vec![Draw::Gutshot]);
+    assert_eq!(draws(&cards("AS 2D 3H 4C KS")), vec![Draw::Gutshot]);
+    assert_eq!(draws(&cards("8S 9D 10C JH QS")), vec![]);
+}
+
+#[test]
+fn test_open_ended_outs_on_the_turn() {
+    let analysis = analyze_outs(&hole_cards("8S 9D"), &cards("10C JH 2S 3D")).unwrap();
+    let straight_outs = Vec::from_iter(analysis.outs.iter()
+        .filter(|out| out.category == RankingCategory::Straight)
+        .map(|out| out.card.rank.value()));
+    assert_eq!(straight_outs.len(), 8);
+    assert!(straight_outs.iter().all(|&value| value == 7 || value == 12));
+    // Pairing the Eight or the Nine helps, pairing the board does not.
+    assert_eq!(analysis.outs.len(), 8 + 2 * 3);
+
+    let (_category, straight_odds) = analysis.odds_by_category.iter()
+        .find(|(category, _odds)| *category == RankingCategory::Straight)
+        .unwrap();
+    assert!((straight_odds - 8.0 / 46.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_invalid_input() {
+    assert_eq!(analyze_outs(&hole_cards("AH KH"), &cards("2H 7H")), Err(OutsError::WrongBoardSize(2)));
+    assert_eq!(
+        analyze_outs(&hole_cards("AH KH"), &cards("2H 7H AH")),
+        Err(OutsError::DuplicateCard(Card::from_string("AH")))
+    );
+}
+
+#[test]
+fn test_board_straight_is_no_out() {
+    // An Ace or a Nine makes a straight on the board itself.
+    let analysis = analyze_outs(&hole_cards("2C 3D"), &cards("10C JH QS KD")).unwrap();
+    assert!(analysis.outs.iter().all(|out| out.category != RankingCategory::Straight));
+    assert_eq!(analysis.outs.len(), 6, "only pairing the Two or the Three");
+}
@@ -0,0 +1,79 @@
+use poker::{Card, Hand, PokerParseError, Rank, Suit, try_winning_hands};
+
+#[test]
+fn test_parse_valid_cards() {
+    let card: Card = "10H".parse().unwrap();
+    assert_eq!(card.rank, Rank::Ten);
+
+    let card = Card::try_from("QS").unwrap();
+    assert_eq!(card.rank, Rank::Queen);
+}
+
+#[test]
+fn test_parse_bad_rank() {
+    assert_eq!(
+        "1H".parse::<Card>(),
+        Err(PokerParseError::BadRank { token: "1".to_string(), offset: 0 })
+    );
+    assert_eq!(
+        "4S 5S 11H 6S 7S".parse::<Hand>().err(),
+        Some(PokerParseError::BadRank { token: "11".to_string(), offset: 6 })
+    );
+}
+
+#[test]
+fn test_parse_bad_suit() {
+    assert_eq!(
+        "4S 5S 7H 6S 10X".parse::<Hand>().err(),
+        Some(PokerParseError::BadSuit { token: "X".to_string(), offset: 14 })
+    );
+}
+
+#[test]
+fn test_parse_wrong_card_count() {
+    assert_eq!(
+        "4S 5S 7H 6S".parse::<Hand>().err(),
+        Some(PokerParseError::WrongCardCount { count: 4 })
+    );
+    assert_eq!("  ".parse::<Hand>().err(), Some(PokerParseError::EmptyInput));
+}
+
+#[test]
+fn test_parse_duplicate_card() {
+    assert_eq!(
+        "4S 5S 7H 4S 8D".parse::<Hand>().err(),
+        Some(PokerParseError::DuplicateCard { token: "4S".to_string(), offset: 9 })
+    );
+}
+
+#[test]
+fn test_try_winning_hands_reports_malformed_hand() {
+    let hands = ["4S 5S 7H 8D JC", "2S 4C 7S 9H 1H"];
+    let error = try_winning_hands(&hands).unwrap_err();
+    assert_eq!(error.index, 1);
+    assert_eq!(error.hand, hands[1]);
+
+    let hands = ["4S 5S 7H 8D JC", "2S 4C 7S 9H 10H"];
+    assert_eq!(try_winning_hands(&hands), Ok(vec![hands[0]]));
+}
+
+#[test]
+fn test_rank_and_suit_enums() {
+    assert_eq!(Rank::try_from('T'), Ok(Rank::Ten));
+    assert_eq!(Suit::try_from('♥'), Ok(Suit::Hearts));
+    assert!(Suit::try_from('Z').is_err());
+    assert!(Rank::Ace > Rank::King);
+    assert_eq!(Rank::all().count(), 13);
+    assert_eq!(Suit::all().map(|s| s.symbol()).collect::<String>(), "♣♦♥♠");
+}
+
+#[test]
+fn test_card_formats() {
+    let card = Card::new(Rank::Ten, Suit::Hearts);
+    assert_eq!("10H".parse::<Card>(), Ok(card));
+    assert_eq!("TH".parse::<Card>(), Ok(card));
+    assert_eq!("10♥".parse::<Card>(), Ok(card));
+    assert_eq!(format!("{}", card), "[10H]");
+    assert_eq!(format!("{:#}", card), "[10♥]");
+    assert_eq!(card.notation(), "10H");
+}
@@ -0,0 +1,74 @@
+use poker::range::{Range, RangeError};
+use poker::RankingCategory;
+
+mod common;
+
+use common::{cards, hole_cards};
+
+#[test]
+fn test_combo_counts() {
+    assert_eq!("TT".parse::<Range>().unwrap().len(), 6);
+    assert_eq!("AKs".parse::<Range>().unwrap().len(), 4);
+    assert_eq!("KQo".parse::<Range>().unwrap().len(), 12);
+    assert_eq!("AK".parse::<Range>().unwrap().len(), 16);
+    assert_eq!("TT+".parse::<Range>().unwrap().len(), 5 * 6);
+    assert_eq!("A5s-A2s".parse::<Range>().unwrap().len(), 4 * 4);
+    assert_eq!("ATs+".parse::<Range>().unwrap().len(), 4 * 4);
+    assert_eq!("77-99".parse::<Range>().unwrap().len(), 3 * 6);
+}
+
+#[test]
+fn test_mixed_range_without_duplicates() {
+    let range: Range = "AKs, TT+, A5s-A2s, KQo".parse().unwrap();
+    assert_eq!(range.len(), 4 + 30 + 16 + 12);
+
+    let overlapping: Range = "AK, AKs, AKo".parse().unwrap();
+    assert_eq!(overlapping.len(), 16);
+}
+
+#[test]
+fn test_combos_are_concrete_cards() {
+    let range: Range = "A2s".parse().unwrap();
+    let hearts = hole_cards("AH 2H");
+    assert!(range.contains(&hearts));
+    assert!(range.contains(&[hearts[1], hearts[0]]));
+    assert!(!range.contains(&hole_cards("AH 2S")));
+}
+
+#[test]
+fn test_remove_blocked() {
+    let mut range: Range = "AA, AKs".parse().unwrap();
+    range.remove_blocked(&cards("AS KH"));
+    // Three Aces left make 3 pairs, and two suits remain for AKs.
+    assert_eq!(range.len(), 3 + 2);
+}
+
+#[test]
+fn test_evaluate_against_board() {
+    let range: Range = "QQ, 76s".parse().unwrap();
+    let board = cards("QS 8H 9H 5C 2D");
+    let evaluated = range.evaluate(&board);
+    assert_eq!(evaluated.len(), 3 + 4);
+
+    let straights = evaluated.iter()
+        .filter(|(_combo, best)| best.hand.ranking_category() == RankingCategory::Straight)
+        .count();
+    let sets = evaluated.iter()
+        .filter(|(_combo, best)| best.hand.ranking_category() == RankingCategory::Trips)
+        .count();
+    assert_eq!((straights, sets), (4, 3));
+}
+
+#[test]
+fn test_invalid_notation() {
+    assert_eq!("".parse::<Range>(), Err(RangeError::EmptyRange));
+    assert_eq!(
+        "AKs, TTs".parse::<Range>(),
+        Err(RangeError::BadToken { token: "TTs".to_string(), offset: 5 })
+    );
+    assert_eq!(
+        "AKs,A5s-K2s".parse::<Range>(),
+        Err(RangeError::BadToken { token: "A5s-K2s".to_string(), offset: 4 })
+    );
+    assert!("AXo".parse::<Range>().is_err());
+}
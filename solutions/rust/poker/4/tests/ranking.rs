@@ -0,0 +1,25 @@
+use poker::{winning_hands, Hand, Rank, RankingCategory};
+
+#[test]
+fn test_category_then_kickers() {
+    let ranking = Hand::from_string("4S 4H 4D AC 2D").ranking();
+    assert_eq!(ranking.category(), RankingCategory::Trips);
+    assert_eq!(ranking.kickers(), vec![Rank::Four, Rank::Ace, Rank::Two]);
+
+    let full = Hand::from_string("3S 3H 3D 2C 2D").ranking();
+    assert!(full > ranking);
+}
+
+#[test]
+fn test_wheel_ace_counts_last() {
+    let wheel = Hand::from_string("AS 2H 3D 4C 5D").ranking();
+    assert_eq!(wheel.kickers(), vec![Rank::Five, Rank::Four, Rank::Three, Rank::Two, Rank::Ace]);
+    assert!(wheel < Hand::from_string("2S 3H 4D 5C 6D").ranking());
+}
+
+#[test]
+fn test_high_kicker_does_not_carry_into_the_next_one() {
+    // Both used to score 3_044_542 once their ranks were packed into decimal digits.
+    let hands = ["4S 4H 4D AC 2D", "4S 4H 4C KD QS"];
+    assert_eq!(winning_hands(&hands), vec!["4S 4H 4D AC 2D"]);
+}
@@ -0,0 +1,27 @@
+use poker::rules::{winning_hands_with, AceToFiveLow, DeuceToSevenLow, High};
+
+#[test]
+fn test_high_rules_match_winning_hands() {
+    let hands = ["4S 5S 7H 8D JC", "2S 4C 7S 9H 10H", "3S 4S 5D 6H JH"];
+    assert_eq!(winning_hands_with(&hands, &High), Ok(poker::winning_hands(&hands)));
+}
+
+#[test]
+fn test_ace_to_five_low() {
+    // The wheel is the nuts, straights and flushes not counting.
+    let hands = ["AS 2H 3D 4C 5S", "2C 3C 4C 5C 7C", "AH 2D 3S 4H 6C"];
+    assert_eq!(winning_hands_with(&hands, &AceToFiveLow), Ok(vec![hands[0]]));
+
+    let hands = ["KS QH JD 9C 8S", "AS AH 2D 3C 4S"];
+    assert_eq!(winning_hands_with(&hands, &AceToFiveLow), Ok(vec![hands[0]]));
+}
+
+#[test]
+fn test_deuce_to_seven_low() {
+    let hands = ["7S 5H 4D 3C 2S", "AS 2H 3D 4C 5S", "7C 5C 4C 3C 2C", "6S 5H 4D 3C 2D"];
+    assert_eq!(winning_hands_with(&hands, &DeuceToSevenLow), Ok(vec![hands[0]]));
+
+    // An Ace plays high, so A-2-3-4-5 is Ace high and loses to King high.
+    let hands = ["AS 2H 3D 4C 5S", "KS 2D 3H 4S 5C"];
+    assert_eq!(winning_hands_with(&hands, &DeuceToSevenLow), Ok(vec![hands[1]]));
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "serde")]
+
+use poker::variant::Variant;
+use poker::{Card, Hand, RankingCategory};
+
+#[test]
+fn test_card_round_trip() {
+    let card = Card::from_string("10H");
+    let json = serde_json::to_string(&card).unwrap();
+    assert_eq!(json, "\"10H\"");
+    assert_eq!(serde_json::from_str::<Card>(&json).unwrap(), card);
+}
+
+#[test]
+fn test_hand_round_trip() {
+    let hand = Hand::from_string("4S 5S 7H 8D QS");
+    let json = serde_json::to_string(&hand).unwrap();
+    assert_eq!(json, r#"["4S","5S","7H","8D","QS"]"#);
+
+    let parsed: Hand = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.cards(), hand.cards());
+    assert_eq!(parsed.ranking(), hand.ranking());
+}
+
+#[test]
+fn test_category_round_trip() {
+    for category in RankingCategory::ALL {
+        let json = serde_json::to_string(&category).unwrap();
+        assert_eq!(serde_json::from_str::<RankingCategory>(&json).unwrap(), category);
+    }
+    assert_eq!(serde_json::to_string(&RankingCategory::Full).unwrap(), "\"full_house\"");
+}
+
+#[test]
+fn test_invalid_input_is_rejected() {
+    assert!(serde_json::from_str::<Card>("\"1H\"").is_err());
+    assert!(serde_json::from_str::<Hand>(r#"["4S","5S","7H","8D"]"#).is_err());
+    assert!(serde_json::from_str::<RankingCategory>("\"royal\"").is_err());
+}
+
+#[test]
+fn test_non_standard_hands_are_not_serialized() {
+    let short_deck = Hand::parse_in("AS 6H 7D 8C 9D", &Variant::short_deck()).unwrap();
+    assert!(serde_json::to_string(&short_deck).is_err());
+}
@@ -0,0 +1,51 @@
+use poker::settlement::{settle, SettlementError, Stake};
+use poker::Hand;
+
+fn stake(seat: usize, contribution: u64, all_in: bool, hand: Option<&str>) -> Stake {
+    Stake { seat, contribution, all_in, folded: hand.is_none(), hand: hand.map(Hand::from_string) }
+}
+
+#[test]
+fn test_side_pots() {
+    let stakes = [
+        stake(0, 50, true, Some("AS AH AD 4C 7S")),
+        stake(1, 200, false, Some("KS KH 9D 9C 2S")),
+        stake(2, 200, false, Some("QS QH 3D 5C 8S")),
+        stake(3, 30, false, None),
+    ];
+    let settlement = settle(&stakes, 3).unwrap();
+
+    assert_eq!(settlement.pots.len(), 2);
+    assert_eq!(settlement.pots[0].amount, 180);
+    assert_eq!(settlement.pots[0].winners, vec![0]);
+    assert_eq!(settlement.pots[1].amount, 300);
+    assert_eq!(settlement.pots[1].winners, vec![1]);
+    assert_eq!(settlement.payouts, vec![180, 300, 0, 0]);
+}
+
+#[test]
+fn test_odd_chip_goes_left_of_button() {
+    let stakes = [
+        stake(1, 51, false, Some("4S 5S 7H 9S JS")),
+        stake(4, 51, false, Some("4H 5H 7S 9H JH")),
+        stake(6, 1, false, None),
+    ];
+    let settlement = settle(&stakes, 3).unwrap();
+    assert_eq!(settlement.payouts, vec![51, 52, 0]);
+    assert_eq!(settlement.payouts.iter().sum::<u64>(), 103);
+}
+
+#[test]
+fn test_uncontested_pot_needs_no_hand() {
+    let stakes = [stake(0, 10, false, None), Stake { hand: None, folded: false, ..stake(1, 20, false, None) }];
+    assert_eq!(settle(&stakes, 0).unwrap().payouts, vec![0, 30]);
+}
+
+#[test]
+fn test_invalid_stakes() {
+    let stakes = [stake(0, 10, false, Some("4S 5S 7H 9S JS")), stake(1, 20, false, Some("4H 5H 7S 9H JH"))];
+    assert_eq!(settle(&stakes, 0), Err(SettlementError::ShortContribution { seat: 0 }));
+
+    let stakes = [stake(0, 10, false, None), stake(1, 10, false, None)];
+    assert_eq!(settle(&stakes, 0), Err(SettlementError::EveryoneFolded));
+}
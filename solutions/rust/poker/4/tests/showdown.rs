@@ -0,0 +1,56 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn showdown(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_showdown"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_hands_as_arguments() {
+    let output = showdown(&["4S 5S 7H 8D QS", "2S 4C 7S 9H 10H"], "");
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "winner: 4S 5S 7H 8D QS\n\
+         4S 5S 7H 8D QS: High card, Queen\n\
+         2S 4C 7S 9H 10H: High card, Ten\n"
+    );
+}
+
+#[test]
+fn test_hands_from_stdin() {
+    let output = showdown(&[], "4S 5H 4C 8D 4H\n\n3S 3H 2S 3D 3C\n");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("winner: 3S 3H 2S 3D 3C\n"), "{}", stdout);
+    assert!(stdout.contains("4S 5H 4C 8D 4H: Three of a kind"));
+}
+
+#[test]
+fn test_json_output() {
+    let output = showdown(&["--json", "4S 5H 4C 8D 4H", "4D AH 4S 8H 4C"], "");
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["winners"], serde_json::json!(["4D AH 4S 8H 4C"]));
+    assert_eq!(json["hands"][0]["category"], "three_of_a_kind");
+    assert_eq!(json["hands"][0]["winner"], false);
+    assert_eq!(json["hands"][1]["winner"], true);
+}
+
+#[test]
+fn test_malformed_input_fails() {
+    let output = showdown(&["4S 5H 4C 8D 4H", "4S 5H 1C 8D 4H"], "");
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr).unwrap().contains("hand #1"));
+
+    assert!(!showdown(&[], "").status.success());
+    assert!(!showdown(&["--colour"], "").status.success());
+}
@@ -0,0 +1,114 @@
+use poker::deck::Deck;
+use poker::game::GameError;
+use poker::stud::{partial_low_ranking, partial_ranking, StudDeal, StudVariant};
+use poker::{Rank, RankingCategory};
+
+mod common;
+
+use common::{cards, stacked_deck};
+
+#[test]
+fn test_partial_rankings() {
+    assert_eq!(partial_ranking(&cards("KS")).category(), RankingCategory::HighestCard);
+    assert_eq!(partial_ranking(&cards("9S 9H")).category(), RankingCategory::OnePair);
+    assert_eq!(partial_ranking(&cards("9S 9H 4C 4D")).category(), RankingCategory::TwoPair);
+    assert_eq!(partial_ranking(&cards("9S 9H 9C")).category(), RankingCategory::Trips);
+    assert_eq!(partial_ranking(&cards("9S 9H 9C 9D")).category(), RankingCategory::Quads);
+
+    assert!(partial_ranking(&cards("2S 2H")) > partial_ranking(&cards("AS KH")));
+    assert!(partial_ranking(&cards("AS 3H")) > partial_ranking(&cards("KS QH")));
+    assert_eq!(partial_ranking(&cards("4S 9H 9C")).kickers(), vec![Rank::Nine, Rank::Four]);
+
+    // In Razz the lowest wins, Aces being low and pairs bad.
+    assert!(partial_low_ranking(&cards("AS 4H")) < partial_low_ranking(&cards("2S 4H")));
+    assert!(partial_low_ranking(&cards("8S 7H")) < partial_low_ranking(&cards("3S 3H")));
+}
+
+#[test]
+fn test_third_street() {
+    // Down, down, then up cards, one at a time around the table.
+    let deck = stacked_deck("2S 3S 4S 5S 6S 7S 9D 4H 4C");
+    let deal = StudDeal::new(StudVariant::SevenCardStud, 3, deck).unwrap();
+    assert_eq!(deal.cards_dealt(), 3);
+    assert_eq!(deal.hands()[0].down, cards("2S 5S"));
+    assert_eq!(deal.hands()[0].up, cards("9D"));
+    assert_eq!(deal.hands()[2].up, cards("4C"));
+}
+
+#[test]
+fn test_stud_bring_in_breaks_ties_by_suit() {
+    let deck = stacked_deck("2S 3S 4S 5S 6S 7S 9D 4H 4C");
+    let deal = StudDeal::new(StudVariant::SevenCardStud, 3, deck).unwrap();
+    // Both Fours are lowest, clubs being below hearts.
+    assert_eq!(deal.bring_in(), 2);
+    assert_eq!(deal.first_to_act(), 2);
+}
+
+#[test]
+fn test_razz_bring_in_is_highest_card() {
+    let deck = stacked_deck("2S 3S 4S 5S 6S 7S AD KH KC");
+    let deal = StudDeal::new(StudVariant::Razz, 3, deck).unwrap();
+    assert_eq!(deal.bring_in(), 1);
+}
+
+#[test]
+fn test_first_to_act_on_later_streets() {
+    let deck = stacked_deck("2S 3S 4S 5S 6S 7S 9D 4H 4C  KD 8H 4D");
+    let mut deal = StudDeal::new(StudVariant::SevenCardStud, 3, deck.clone()).unwrap();
+    deal.deal_next_street().unwrap();
+    assert_eq!(deal.first_to_act(), 2, "the pair of Fours shows the best");
+
+    deal.fold(2);
+    assert_eq!(deal.first_to_act(), 0, "King high beats Eight high");
+
+    let mut razz = StudDeal::new(StudVariant::Razz, 3, deck).unwrap();
+    razz.deal_next_street().unwrap();
+    assert_eq!(razz.first_to_act(), 1, "8-4 is the lowest");
+}
+
+#[test]
+fn test_dealing_to_the_river_and_showdown() {
+    let deck = stacked_deck(
+        "AS KS QS JS 10S 9S 2H 3H AH  AD 4H 5D  AC 6H 7D  8C 8H 7C  9C 3C 2C"
+    );
+    let mut deal = StudDeal::new(StudVariant::SevenCardStud, 3, deck.clone()).unwrap();
+    for _ in 4..=7 {
+        deal.deal_next_street().unwrap();
+    }
+    assert_eq!(deal.cards_dealt(), 7);
+    assert_eq!(deal.hands()[0].down.len(), 3);
+    assert_eq!(deal.hands()[0].up.len(), 4);
+    assert!(matches!(deal.deal_next_street(), Err(GameError::HandOver)));
+    // Trip Aces for seat 0 against two small pairs.
+    assert_eq!(deal.winners(), vec![0]);
+
+    let mut razz = StudDeal::new(StudVariant::Razz, 3, deck).unwrap();
+    for _ in 4..=7 {
+        razz.deal_next_street().unwrap();
+    }
+    // 9-7-5-2-A for seat 2 beats J-9-8-2-A and 10-8-6-4-3.
+    assert_eq!(razz.winners(), vec![2]);
+}
+
+#[test]
+fn test_eight_players_share_a_community_river_card() {
+    let mut deal = StudDeal::new(StudVariant::SevenCardStud, 8, Deck::new()).unwrap();
+    for _ in 4..=6 {
+        deal.deal_next_street().unwrap();
+    }
+    // 48 cards are out, leaving 4 for 8 players.
+    deal.deal_next_street().unwrap();
+    assert!(deal.community_card().is_some());
+    assert!(deal.hands().iter().all(|hand| hand.down.len() == 2 && hand.up.len() == 4));
+    assert_eq!(deal.cards_dealt(), 7);
+    assert!(matches!(deal.deal_next_street(), Err(GameError::HandOver)));
+    assert!(!deal.winners().is_empty());
+}
+
+#[test]
+fn test_player_limits() {
+    assert!(matches!(StudDeal::new(StudVariant::Razz, 1, Deck::new()), Err(GameError::NotEnoughPlayers)));
+    assert!(StudDeal::new(StudVariant::Razz, 2, Deck::new()).is_ok());
+    assert!(StudDeal::new(StudVariant::Razz, 8, Deck::new()).is_ok());
+    assert!(matches!(StudDeal::new(StudVariant::Razz, 9, Deck::new()), Err(GameError::TooManyPlayers { max: 8 })));
+}
@@ -0,0 +1,97 @@
+use poker::deck::{Deck, DeckError};
+use poker::game::Blinds;
+use poker::tournament::{chip_up, icm_equity, BlindLevel, BlindSchedule, TournamentError};
+use poker::Card;
+
+fn assert_close(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-9, "{:?} != {:?}", actual, expected);
+    }
+}
+
+#[test]
+fn test_icm_three_players() {
+    let equities = icm_equity(&[5000, 3000, 2000], &[50.0, 30.0, 20.0]).unwrap();
+    assert_close(&equities, &[38.392857142857146, 32.75, 28.857142857142858]);
+}
+
+#[test]
+fn test_icm_properties() {
+    assert_close(&icm_equity(&[100, 100, 100, 100], &[60.0, 40.0]).unwrap(), &[25.0; 4]);
+    // Heads-up, second place is locked up and first goes by the share of chips.
+    assert_close(&icm_equity(&[3000, 1000], &[70.0, 30.0]).unwrap(), &[60.0, 40.0]);
+    // Busted players get nothing, and the places they can't take are not paid.
+    assert_close(&icm_equity(&[0, 500, 500], &[50.0, 30.0, 20.0]).unwrap(), &[0.0, 40.0, 40.0]);
+
+    assert_eq!(icm_equity(&[0, 0], &[10.0]), Err(TournamentError::NoChipsInPlay));
+}
+
+#[test]
+fn test_icm_favours_short_stacks() {
+    // A chip leader gets less than its share of the chips.
+    let equities = icm_equity(&[7000, 2000, 1000], &[50.0, 30.0, 20.0]).unwrap();
+    assert!(equities[0] < 0.7 * 100.0);
+    assert!(equities[2] > 0.1 * 100.0);
+    assert!((equities.iter().sum::<f64>() - 100.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_icm_size_cap() {
+    // 20 players with 8 paid places track C(20, 7) = 77_520 sets, within the cap...
+    let stacks = Vec::from_iter((1..=20).map(|i| i * 100));
+    let payouts = [30.0, 20.0, 15.0, 10.0, 8.0, 7.0, 6.0, 4.0];
+    let equities = icm_equity(&stacks, &payouts).unwrap();
+    assert!((equities.iter().sum::<f64>() - 100.0).abs() < 1e-9);
+    assert!(equities.windows(2).all(|pair| pair[0] < pair[1]));
+    // ...while a ninth place needs C(20, 8) = 125_970 of them.
+    assert_eq!(
+        icm_equity(&stacks, &[payouts.as_slice(), &[3.0]].concat()),
+        Err(TournamentError::TooManyPlaces { players: 20, places: 9 })
+    );
+
+    let stacks = [1000; 64];
+    assert_close(&icm_equity(&stacks, &[40.0, 30.0, 20.0, 10.0]).unwrap(), &[100.0 / 64.0; 64]);
+    assert_eq!(
+        icm_equity(&stacks, &[40.0, 25.0, 15.0, 12.0, 8.0]),
+        Err(TournamentError::TooManyPlaces { players: 64, places: 5 })
+    );
+    assert_eq!(icm_equity(&[1000; 65], &[100.0]), Err(TournamentError::TooManyPlayers(65)));
+}
+
+#[test]
+fn test_blind_schedule() {
+    let level = |small, big, ante| BlindLevel { blinds: Blinds { small, big }, ante, minutes: 20 };
+    let schedule = BlindSchedule::new(vec![level(25, 50, 0), level(50, 100, 0), level(100, 200, 25)]).unwrap();
+    assert_eq!(schedule.level_index_at(0), 0);
+    assert_eq!(schedule.level_index_at(19), 0);
+    assert_eq!(schedule.level_index_at(20), 1);
+    assert_eq!(schedule.level_at(45).ante, 25);
+    assert_eq!(schedule.level_at(500).blinds.big, 200);
+
+    assert_eq!(BlindSchedule::new(vec![]), Err(TournamentError::NoBlindLevels));
+}
+
+#[test]
+fn test_chip_up_race_off() {
+    // Dealt from the top: player 0 gets 2S, player 1 gets KD and 3C, player 2 gets AH.
+    let order = ["2S", "KD", "3C", "AH"];
+    let mut deck = Deck::from_cards(order.iter().rev().map(|c| Card::from_string(c)).collect()).unwrap();
+
+    // 25 chips colour up into 100 chips: 4 odd chips are worth one 100 chip, won by the Ace.
+    let large = chip_up(&[5, 10, 1], 25, 100, &mut deck).unwrap();
+    assert_eq!(large, vec![1, 2, 1]);
+    assert!(deck.is_empty());
+
+    assert_eq!(
+        chip_up(&[3], 25, 60, &mut Deck::new()),
+        Err(TournamentError::BadDenominations { small: 25, large: 60 })
+    );
+    // 20 players with 3 odd chips each need 60 cards: none is dealt.
+    let mut deck = Deck::new();
+    assert_eq!(
+        chip_up(&[3; 20], 25, 100, &mut deck),
+        Err(TournamentError::Deck(DeckError::NotEnoughCards { needed: 60, left: 52 }))
+    );
+    assert_eq!(deck.len(), 52);
+}
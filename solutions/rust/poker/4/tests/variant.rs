@@ -0,0 +1,111 @@
+use poker::deck::{Deck, DeckError};
+use poker::variant::{winning_hands_in, Variant};
+use poker::{Card, Hand, PokerParseError, Rank, RankingCategory};
+
+mod common;
+
+use common::cards;
+
+#[test]
+fn test_short_deck_composition() {
+    let short_deck = Variant::short_deck();
+    assert_eq!(Deck::for_variant(&short_deck).len(), 36);
+    assert_eq!(short_deck.ranks().next(), Some(Rank::Six));
+    assert_eq!(
+        Hand::parse_in("2S 6H 7D 8C 9D", &short_deck).err(),
+        Some(PokerParseError::BadRank { token: "2".to_string(), offset: 0 })
+    );
+}
+
+#[test]
+fn test_short_deck_lowest_straight() {
+    let short_deck = Variant::short_deck();
+    let hand = Hand::parse_in("AS 6H 7D 8C 9D", &short_deck).unwrap();
+    assert_eq!(hand.ranking_category(), RankingCategory::Straight);
+    assert_eq!(hand.ranking().kickers()[0], Rank::Nine);
+
+    let higher = Hand::parse_in("6S 7H 8D 9C 10D", &short_deck).unwrap();
+    assert!(higher.ranking() > hand.ranking());
+
+    // Without the short deck, A-6-7-8-9 is just Ace high.
+    assert_eq!(Hand::from_string("AS 6H 7D 8C 9D").ranking_category(), RankingCategory::HighestCard);
+}
+
+#[test]
+fn test_short_deck_flush_beats_full_house() {
+    let hands = ["6H 8H 9H JH KH", "QS QH QD 7C 7D"];
+    assert_eq!(winning_hands_in(&hands, &Variant::short_deck()).unwrap(), vec!["6H 8H 9H JH KH"]);
+    assert_eq!(winning_hands_in(&hands, &Variant::standard()).unwrap(), vec!["QS QH QD 7C 7D"]);
+}
+
+#[test]
+fn test_short_deck_best_hand() {
+    let best = Variant::short_deck().best_hand(&cards("AH 9S 6D 7C 8H KS QD")).unwrap();
+    assert_eq!(best.hand.ranking_category(), RankingCategory::Straight);
+}
+
+#[test]
+fn test_multiple_decks() {
+    let two_decks = Variant::standard().with_decks(2);
+    assert_eq!(Deck::for_variant(&two_decks).len(), 104);
+
+    let five_aces = Hand::parse_in("AS AS AH AD AC", &two_decks).unwrap();
+    assert_eq!(five_aces.ranking_category(), RankingCategory::FiveOfAKind);
+
+    let flush_pair = Hand::parse_in("KH KH 9H 5H 2H", &two_decks).unwrap();
+    assert_eq!(flush_pair.ranking_category(), RankingCategory::Flush);
+
+    assert!(matches!(
+        Hand::parse_in("AS AS AS KD QC", &two_decks),
+        Err(PokerParseError::DuplicateCard { offset: 6, .. })
+    ));
+    assert!(matches!(
+        Hand::parse_in("AS AS KH QD JC", &Variant::standard()),
+        Err(PokerParseError::DuplicateCard { offset: 3, .. })
+    ));
+}
+
+#[test]
+fn test_paired_flush_ranks_by_its_top_cards() {
+    let two_decks = Variant::standard().with_decks(2);
+    let paired_flush = ["2H 2H 9H 5H KH", "3H 4H 6H 7H 8H"];
+    assert_eq!(winning_hands_in(&paired_flush, &two_decks).unwrap(), vec!["2H 2H 9H 5H KH"]);
+
+    let hand = Hand::parse_in("2H 2H 9H 5H KH", &two_decks).unwrap();
+    assert_eq!(hand.ranking().kickers(), vec![Rank::King, Rank::Nine, Rank::Five, Rank::Two, Rank::Two]);
+    assert_eq!(hand.describe(), "Flush, King high");
+}
+
+#[test]
+fn test_multi_deck_decks_rebuild() {
+    let two_decks = Variant::standard().with_decks(2);
+    let mut deck = Deck::for_variant(&two_decks);
+    let rebuilt = Deck::from_cards_in(deck.cards().to_vec(), &two_decks).unwrap();
+    assert_eq!(rebuilt.len(), 104);
+    assert!(Deck::from_cards(deck.cards().to_vec()).is_err());
+
+    let ace = Card::from_string("AS");
+    assert_eq!(deck.return_card(ace), Err(DeckError::DuplicateCard(ace)));
+    deck.remove(&ace).unwrap();
+    assert_eq!(deck.return_card(ace), Ok(()));
+
+    let three_aces = cards("AS AS AS");
+    assert_eq!(Deck::from_cards_in(three_aces, &two_decks).err(), Some(DeckError::DuplicateCard(ace)));
+    assert_eq!(
+        Deck::from_cards_in(cards("2S"), &Variant::short_deck()).err(),
+        Some(DeckError::CardNotInDeck(Card::from_string("2S")))
+    );
+}
+
+#[test]
+fn test_custom_category_order() {
+    let mut order = RankingCategory::ALL;
+    order.swap(1, 2);
+    let variant = Variant::standard().with_category_order(order).unwrap();
+    assert_eq!(variant.category_order(), order);
+    assert!(variant.strength(RankingCategory::OnePair) > variant.strength(RankingCategory::TwoPair));
+
+    // Every category must be there, once.
+    order[2] = RankingCategory::HighestCard;
+    assert_eq!(Variant::standard().with_category_order(order), None);
+}
@@ -0,0 +1,39 @@
+use poker::wild::{best_wild_hand, parse_wild_hand, winning_wild_hands, WildCard, WildRules};
+use poker::{Card, RankingCategory};
+
+fn best(input: &str, rules: &WildRules) -> RankingCategory {
+    best_wild_hand(&parse_wild_hand(input).unwrap(), rules).ranking.category
+}
+
+#[test]
+fn test_parse_joker() {
+    let cards = parse_wild_hand("AS JK 3D * 5C").unwrap();
+    assert_eq!(cards[1], WildCard::Joker);
+    assert_eq!(cards[3], WildCard::Joker);
+    assert_eq!(cards[0], WildCard::Natural(Card::from_string("AS")));
+}
+
+#[test]
+fn test_jokers_complete_the_best_hand() {
+    let rules = WildRules::jokers_only();
+    assert_eq!(best("AS AH AD AC JK", &rules), RankingCategory::FiveOfAKind);
+    assert_eq!(best("9H 10H JH QH JK", &rules), RankingCategory::StraightFlush);
+    assert_eq!(best("4S 4H 9D 9C JK", &rules), RankingCategory::Full);
+    assert_eq!(best("2S 7H 9D KC JK", &rules), RankingCategory::OnePair);
+}
+
+#[test]
+fn test_deuces_wild() {
+    let rules = WildRules::deuces_wild();
+    assert_eq!(best("2S 2H KD KC 7H", &rules), RankingCategory::Quads);
+    assert_eq!(best("2S 7H 8H 9H 10H", &rules), RankingCategory::StraightFlush);
+
+    let hand = best_wild_hand(&parse_wild_hand("2S 7H 8H 9H 10H").unwrap(), &rules);
+    assert!(hand.cards.contains(&Card::from_string("JH")));
+}
+
+#[test]
+fn test_five_of_a_kind_beats_royal_flush() {
+    let hands = ["10H JH QH KH AH", "3S 3H 3D 3C JK"];
+    assert_eq!(winning_wild_hands(&hands, &WildRules::jokers_only()), Ok(vec![hands[1]]));
+}